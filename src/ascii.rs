@@ -0,0 +1,331 @@
+use crate::{builder::GridBuilder, error::LayoutError};
+use egui_extras::Size;
+
+impl GridBuilder {
+    /// Build a grid from a drawing of boxes, handy for prototyping & tests.
+    ///
+    /// Rows are separated by lines of `+` & `-`, and the cells within them by `|`.
+    /// Rows are sized by how many lines tall they're drawn, and cells by how many characters wide, relative to the rest.
+    /// The last row & the last cell of each row use [`Size::remainder`](https://docs.rs/egui_extras/latest/egui_extras/enum.Size.html#method.remainder), taking up whatever the spacing leaves.
+    /// Any text within a cell becomes its name (see [`Self::named`]).
+    ///
+    /// Each row is drawn on its own, so cells don't have to line up with the rows around them. Cells can't span several rows; nest a grid instead.
+    ///
+    /// ## Example
+    /// ```
+    /// # use egui_grid::{GridBuilder, LayoutError};
+    /// # fn demo(ui: &mut egui::Ui) {
+    /// let grid = GridBuilder::from_ascii("
+    ///     +-------------------+
+    ///     | toolbar           |
+    ///     +-----+-------------+
+    ///     | nav | content     |
+    ///     |     |             |
+    ///     |     |             |
+    ///     +-----+-------------+
+    /// ").unwrap();
+    ///
+    /// grid.show(ui, |mut grid| {
+    ///     grid.named("toolbar", |ui| {
+    ///         ui.label("Toolbar");
+    ///     });
+    ///     grid.named("nav", |ui| {
+    ///         ui.label("Navigation");
+    ///     });
+    ///     grid.named("content", |ui| {
+    ///         ui.label("Content");
+    ///     });
+    /// });
+    /// # }
+    ///
+    /// // Mistakes are pointed out by line & column
+    /// let error = GridBuilder::from_ascii("
+    ///     +-----+-----+
+    ///     | a   | b   |
+    ///     | a     |   |
+    ///     +-----+-----+
+    /// ");
+    /// assert!(matches!(error, Err(LayoutError::Parse { line: 4, column: 13, .. })));
+    /// ```
+    pub fn from_ascii(drawing: &str) -> Result<GridBuilder, LayoutError> {
+        let lines: Vec<Line> = drawing
+            .lines()
+            .enumerate()
+            .filter(|(_, text)| !text.trim().is_empty())
+            .map(|(index, text)| Line::new(index + 1, text))
+            .collect();
+
+        let (first, last) = match (lines.first(), lines.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return Err(error(1, 1, "the drawing is empty")),
+        };
+        for line in [first, last] {
+            if !line.is_border() {
+                return Err(error(
+                    line.number,
+                    line.indent + 1,
+                    "expected the drawing to start & end with a line of `+` & `-`",
+                ));
+            }
+        }
+
+        // Gather the lines of each row, between borders
+        let mut rows: Vec<&[Line]> = Vec::new();
+        let mut start = 1;
+        for (index, line) in lines.iter().enumerate().skip(1) {
+            if line.is_border() {
+                if index == start {
+                    return Err(error(
+                        line.number,
+                        line.indent + 1,
+                        "expected a row between these borders",
+                    ));
+                }
+                rows.push(&lines[start..index]);
+                start = index + 1;
+            } else if line.chars[0] != '|' {
+                return Err(error(
+                    line.number,
+                    line.indent + 1,
+                    "expected a row of cells starting with `|`, or a border made up of only `+` & `-` (cells can't span several rows, nest a grid instead)",
+                ));
+            }
+        }
+        if rows.is_empty() {
+            return Err(error(
+                first.number,
+                first.indent + 1,
+                "expected at least one row",
+            ));
+        }
+        let total_height: usize = rows.iter().map(|row| row.len()).sum();
+
+        let mut grid = GridBuilder::new();
+        for (row_index, row) in rows.iter().enumerate() {
+            let size = if row_index + 1 == rows.len() {
+                Size::remainder()
+            } else {
+                Size::relative(row.len() as f32 / total_height as f32)
+            };
+            grid = grid.new_row(size);
+
+            // The first line decides where the cells are, the rest have to agree
+            let first = &row[0];
+            let dividers = first.dividers();
+            if dividers.len() < 2
+                || dividers[0] != 0
+                || dividers[dividers.len() - 1] != first.chars.len() - 1
+            {
+                return Err(error(
+                    first.number,
+                    first.indent + 1,
+                    "expected the row to start & end with `|`",
+                ));
+            }
+            for line in row.iter().skip(1) {
+                let other = line.dividers();
+                if other != dividers {
+                    // Point at the first `|` out of place
+                    let column = match other.iter().zip(&dividers).find(|(a, b)| a != b) {
+                        Some((a, _)) => *a,
+                        None if other.len() > dividers.len() => other[dividers.len()],
+                        None => dividers[other.len()],
+                    };
+                    return Err(error(
+                        line.number,
+                        line.indent + column + 1,
+                        "the `|` between cells don't line up with the first line of the row",
+                    ));
+                }
+            }
+
+            let total_width = (dividers[dividers.len() - 1] - dividers[0]) as f32;
+            for (cell_index, edges) in dividers.windows(2).enumerate() {
+                let size = if cell_index + 2 == dividers.len() {
+                    Size::remainder()
+                } else {
+                    Size::relative((edges[1] - edges[0]) as f32 / total_width)
+                };
+                grid = grid.cell(size);
+
+                let name = row
+                    .iter()
+                    .map(|line| {
+                        line.chars[edges[0] + 1..edges[1]]
+                            .iter()
+                            .collect::<String>()
+                    })
+                    .map(|text| text.trim().to_string())
+                    .find(|text| !text.is_empty());
+                if let Some(name) = name {
+                    grid = grid.named(name);
+                }
+            }
+        }
+        Ok(grid)
+    }
+}
+
+// A line of the drawing, without its indentation
+struct Line {
+    number: usize,
+    indent: usize,
+    chars: Vec<char>,
+}
+
+impl Line {
+    fn new(number: usize, text: &str) -> Line {
+        let trimmed = text.trim_start();
+        Line {
+            number,
+            indent: text[..text.len() - trimmed.len()].chars().count(),
+            chars: trimmed.trim_end().chars().collect(),
+        }
+    }
+
+    fn is_border(&self) -> bool {
+        self.chars.iter().all(|c| *c == '+' || *c == '-')
+    }
+
+    // Where each `|` is
+    fn dividers(&self) -> Vec<usize> {
+        self.chars
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| **c == '|')
+            .map(|(index, _)| index)
+            .collect()
+    }
+}
+
+fn error(line: usize, column: usize, message: &str) -> LayoutError {
+    LayoutError::Parse {
+        line,
+        column,
+        message: message.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::Env;
+    use egui::{pos2, Id, Rect, Vec2};
+
+    // The name & outer rect of each cell, laid out in a 200 by 200 square
+    fn lay_out(grid: &GridBuilder, spacing: f32) -> Vec<(Option<String>, Rect)> {
+        let rect = Rect::from_min_max(pos2(0., 0.), pos2(200., 200.));
+        let env = Env {
+            ctx: None,
+            spacing: Vec2::splat(spacing),
+        };
+        grid.to_real_cells(rect, rect, Id::new("ascii"), &env)
+            .cells
+            .iter()
+            .map(|cell| (cell.name().map(str::to_string), cell.outer()))
+            .collect()
+    }
+
+    // Where a drawing goes wrong
+    fn error_at(drawing: &str) -> (usize, usize) {
+        match GridBuilder::from_ascii(drawing) {
+            Err(LayoutError::Parse { line, column, .. }) => (line, column),
+            Err(other) => panic!("expected a parse error, got {other}"),
+            Ok(_) => panic!("expected the drawing to be rejected"),
+        }
+    }
+
+    fn rect(min: (f32, f32), max: (f32, f32)) -> Rect {
+        Rect::from_min_max(pos2(min.0, min.1), pos2(max.0, max.1))
+    }
+
+    #[test]
+    fn sizes_are_proportional_to_the_drawing() {
+        let grid = GridBuilder::from_ascii(
+            "
+            +----+--------------+
+            | a  |              |
+            +----+--------------+
+            |      |            |
+            |      |   body     |
+            |      |            |
+            +------+------------+
+            ",
+        )
+        .unwrap();
+        let cells = lay_out(&grid, 0.);
+        assert_eq!(
+            cells,
+            vec![
+                (Some("a".to_string()), rect((0., 0.), (50., 50.))),
+                (None, rect((50., 0.), (200., 50.))),
+                (None, rect((0., 50.), (70., 200.))),
+                (Some("body".to_string()), rect((70., 50.), (200., 200.))),
+            ]
+        );
+
+        // The last row & cell take up whatever the spacing leaves
+        let cells = lay_out(&grid, 10.);
+        assert_eq!(cells[1].1.max.x, 200.);
+        assert_eq!(cells[3].1.max, pos2(200., 200.));
+        assert_eq!(cells[1].1.width(), 200. - cells[0].1.width() - 10.);
+    }
+
+    #[test]
+    fn names_come_from_any_line_of_the_cell() {
+        let grid = GridBuilder::from_ascii(
+            "
+            +----------+-------+
+            |          |  two  |
+            |   one    | words |
+            +----------+-------+
+            ",
+        )
+        .unwrap();
+        let names: Vec<Option<String>> = lay_out(&grid, 0.)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        // The first line with any text wins
+        assert_eq!(
+            names,
+            vec![Some("one".to_string()), Some("two".to_string())]
+        );
+    }
+
+    #[test]
+    fn indented_and_non_ascii_drawings_are_counted_in_characters() {
+        let grid =
+            GridBuilder::from_ascii("\t\t+----+---+\n\t\t| 名前 | b |\n\t\t+----+---+").unwrap();
+        let cells = lay_out(&grid, 0.);
+        assert_eq!(cells[0].0.as_deref(), Some("名前"));
+        assert_eq!(cells[0].1.width(), 200. * 5. / 9.);
+        assert_eq!(cells[1].0.as_deref(), Some("b"));
+
+        // Columns count characters from the start of the line, indentation included
+        assert_eq!(
+            error_at("\t\t+----+---+\n\t\t| 名前 | b |\n\t\t| 名前| b |\n\t\t+----+---+"),
+            (3, 7)
+        );
+    }
+
+    #[test]
+    fn mistakes_are_pointed_out_by_line_and_column() {
+        assert_eq!(error_at(""), (1, 1));
+        assert_eq!(error_at("   \n\t"), (1, 1));
+        // Not starting or ending with a border
+        assert_eq!(error_at("  | a |\n  +---+"), (1, 3));
+        assert_eq!(error_at("+---+\n| a |"), (2, 1));
+        // No row between borders
+        assert_eq!(error_at("+---+\n+---+"), (2, 1));
+        assert_eq!(error_at("   +---+"), (1, 4));
+        // A line that's neither a row nor a border
+        assert_eq!(error_at("+---+\n| a |\n a  |\n+---+"), (3, 2));
+        // A row not closed off with `|`
+        assert_eq!(error_at("+---+\n| a  \n+---+"), (2, 1));
+        // Dividers out of place, moved, added or missing
+        assert_eq!(error_at("+-----+\n| a | |\n| a  ||\n+-----+"), (3, 6));
+        assert_eq!(error_at("+----+\n|   |\n|   ||\n+----+"), (3, 6));
+        assert_eq!(error_at("+-----+\n|  | |\n|  |\n+-----+"), (3, 6));
+    }
+}
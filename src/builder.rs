@@ -1,6 +1,26 @@
-use crate::{grid::*, helper::*};
-use egui::{Align, Layout, Margin, Pos2, Rect, Response, Sense, Ui, Vec2};
+use crate::{
+    collapse::{Collapse, Collapsed},
+    grid::*,
+    helper::*,
+    reorder::ReorderMode,
+    selection::{Selection, SelectionMode},
+    style::{CellFrame, SeparatorRow, Separators},
+    transition::transition,
+};
+use egui::{Align, Context, Id, Layout, Margin, Pos2, Rangef, Rect, Sense, Stroke, Ui, Vec2};
 use egui_extras::Size;
+use std::borrow::Cow;
+
+mod editor;
+mod html;
+mod inspector;
+mod mutable;
+#[cfg(feature = "serde")]
+mod schema;
+mod svg;
+
+pub use editor::LayoutEditor;
+pub use mutable::CellMut;
 
 /// Builder for creating a new [`Grid`].
 ///
@@ -11,17 +31,22 @@ use egui_extras::Size;
 /// Allocate new rows using [`Self::new_row`], with the size given being what the row's cells will inherit.
 /// Then populate the row with cells using [`Self::cell`] or [`Self::cells`], each cell having it's own horizontal size and inheriting the size of the row it's being placed in.
 /// Since cells do not wrap, [`Self::new_row`] can be called again to allocate a new row which can be populated with more cells.
+/// For cells which do wrap, see [`Self::new_flow`].
 ///
 /// Build the grid using [`Self::show`], and add it's contents to the ui using [`Grid::cell`].
 /// Will panic if the number of cells called to display is more than the amount pre-allocated.
 ///
 /// One can customize how the grid gets built and how the cells are displayed using [`Self::rows_as_columns`], [`Self::spacing`], [`Self::align`], among others.
 ///
+/// With the `serde` feature enabled, builders can be serialized & deserialized, letting layouts be saved or shipped as config files.
+/// Layouts are saved along with the version of the format they were saved in, so older layouts keep loading as the crate changes.
+///
 /// ## Exmaple
 /// ```
 /// use egui_grid::GridBuilder;
 /// use egui_extras::Size;
 ///
+/// # fn demo(ui: &mut egui::Ui) {
 /// GridBuilder::new()
 ///     // Allocate a new row
 ///     .new_row(Size::exact(200.0))
@@ -48,16 +73,33 @@ use egui_extras::Size;
 ///             ui.label("Bottom row, right cell");
 ///         });
 ///     });
+/// # }
 /// ```
 #[derive(Clone)]
 pub struct GridBuilder {
     units: Vec<Row>,
     spacing: Vec2,
-    row_as_col: bool,
+    orientation: Orientation,
     creation_cache: Vec<(usize, usize)>,
     clip: bool,
     use_default_spacing: bool,
     default_layout: Layout,
+    frozen: usize,
+    id_source: Option<Id>,
+    breakpoints: Vec<(Breakpoint, GridBuilder)>,
+    mirror_x: bool,
+    mirror_y: bool,
+    rtl: bool,
+    frame: Option<CellFrame>,
+    separators: Option<Separators>,
+    striped: bool,
+    highlight_rows: bool,
+    selection: Option<SelectionMode>,
+    select_rows: bool,
+    keyboard_navigation: bool,
+    reorder: Option<ReorderMode>,
+    animate: Option<f32>,
+    debug: bool,
 }
 
 impl Default for GridBuilder {
@@ -65,11 +107,27 @@ impl Default for GridBuilder {
         GridBuilder {
             units: Vec::new(),
             spacing: Vec2::ZERO,
-            row_as_col: false,
+            orientation: Orientation::Rows,
             creation_cache: Vec::new(),
             clip: false,
             use_default_spacing: true,
             default_layout: Layout::default(),
+            frozen: 0,
+            id_source: None,
+            breakpoints: Vec::new(),
+            mirror_x: false,
+            mirror_y: false,
+            rtl: false,
+            frame: None,
+            separators: None,
+            striped: false,
+            highlight_rows: false,
+            selection: None,
+            select_rows: false,
+            keyboard_navigation: false,
+            reorder: None,
+            animate: None,
+            debug: false,
         }
     }
 }
@@ -102,6 +160,16 @@ impl GridBuilder {
         self
     }
 
+    /// A source for the grid's unique [`Id`](https://docs.rs/egui/latest/egui/struct.Id.html), e.g. `.id_source("gallery")` or `.id_source(loop_index)`.
+    ///
+    /// Without one, the grid's id comes from its position in the ui, so it's only needed when grids that remember something between frames
+    /// (such as [`Self::new_masonry`]) may come & go or change order.
+    /// Nested grids get their id from the grid they're nested in.
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
     /// Should we clip the contents of each cell? Default: `false`.
     ///
     /// If set to `true`, cells will hide whatever part(s) of any ui that spill outside of the cell's defined area.
@@ -127,6 +195,102 @@ impl GridBuilder {
         self
     }
 
+    /// Allocate a flow, a row whose cells wrap onto as many rows as the available width requires.
+    /// Behaves like the CSS `repeat(auto-fill, minmax(min_width, max_width))`.
+    ///
+    /// `amount` cells are placed, each being at least `min_width` wide. As many cells as can fit are put in each row,
+    /// and their widths are stretched to fill the row (but never past `max_width`). Every row the flow wraps onto is given the `height` provided.
+    /// A `min_width` below 1 point is treated as 1.
+    ///
+    /// The flow's cells act as one, so [`Self::with_margin`], [`Self::with_layout`] and [`Self::nest`] will apply to all of them.
+    /// Cells can't be added to a flow using [`Self::cell`] or [`Self::cells`].
+    ///
+    /// ## Example
+    /// ```
+    /// # use egui_grid::GridBuilder;
+    /// # use egui_extras::Size;
+    /// # use egui::Margin;
+    /// # fn demo(ui: &mut egui::Ui, cards: &[&str]) {
+    /// GridBuilder::new()
+    ///     // A gallery of cards between 120 and 200 points wide, each 160 points tall
+    ///     .new_flow(Size::exact(160.0), 120.0, 200.0, cards.len() as i32)
+    ///     .with_margin(Margin::same(4.0))
+    ///     .show(ui, |mut grid| {
+    ///         for card in cards {
+    ///             grid.cell(|ui| {
+    ///                 ui.label(*card);
+    ///             });
+    ///         }
+    ///     });
+    /// # }
+    /// ```
+    pub fn new_flow(mut self, height: Size, min_width: f32, max_width: f32, amount: i32) -> Self {
+        let min_width = min_width.max(1.);
+        let mut row = Row::new(height, Align::Min);
+        row.flow = Some(Flow {
+            width: Rangef::new(min_width, max_width.max(min_width)),
+            amount: amount.max(0) as usize,
+        });
+        row.cells.push(Cell::new(
+            Size::exact(min_width),
+            Margin::same(0.),
+            self.default_layout,
+        ));
+        self.units.push(row);
+        self.creation_cache = vec![(self.units.len() - 1, 0)];
+        self
+    }
+
+    /// Allocate a masonry, a row which splits its width into `columns` equally sized columns.
+    /// Cells added to it are placed one after the other, each into whichever column is currently the shortest.
+    ///
+    /// Cells are given to a masonry with [`Self::cell`] or [`Self::cells`], only now the [`Size`](https://docs.rs/egui_extras/latest/egui_extras/enum.Size.html) given is the cell's *height*:
+    /// - `Size::exact` is used as is.
+    /// - `Size::relative` is relative to the width of a column, making it handy for keeping the aspect ratio of images.
+    /// - `Size::remainder` is measured from the height of the cell's contents (plus its margin & frame) the frame before (until it can be, the width of a column is used).
+    ///
+    /// The masonry will be as tall as its tallest column, and cells are represented in the order they were added.
+    ///
+    /// ## Example
+    /// ```
+    /// # use egui_grid::GridBuilder;
+    /// # use egui_extras::Size;
+    /// # fn demo(ui: &mut egui::Ui, notes: &[&str]) {
+    /// GridBuilder::new()
+    ///     .new_masonry(3)
+    ///     // A picture with a 4:3 aspect ratio
+    ///     .cell(Size::relative(0.75))
+    ///     // Notes which are as tall as their text
+    ///     .cells(Size::remainder(), notes.len() as i32)
+    ///     .show(ui, |mut grid| {
+    ///         grid.cell(|ui| {
+    ///             ui.label("Picture goes here");
+    ///         });
+    ///         for note in notes {
+    ///             grid.cell(|ui| {
+    ///                 ui.label(*note);
+    ///             });
+    ///         }
+    ///     });
+    /// # }
+    /// ```
+    pub fn new_masonry(mut self, columns: i32) -> Self {
+        let mut row = Row::new(Size::remainder(), Align::Min);
+        row.masonry = Some(Masonry::new(MasonryColumns::Fixed(columns.max(1) as usize)));
+        self.units.push(row);
+        self
+    }
+
+    /// Allocate a masonry, fitting in as many columns at least `min_width` wide as possible (a `min_width` below 1 point being treated as 1).
+    ///
+    /// Behavior otherwise matches [`Self::new_masonry`].
+    pub fn new_masonry_auto(mut self, min_width: f32) -> Self {
+        let mut row = Row::new(Size::remainder(), Align::Min);
+        row.masonry = Some(Masonry::new(MasonryColumns::Auto(min_width.max(1.))));
+        self.units.push(row);
+        self
+    }
+
     /// Set the cell [`Align`](https://docs.rs/egui/latest/egui/enum.Align.html) of the most recently allocated row.
     /// This will work regardless if the row has been populated with cells or not.
     ///
@@ -139,6 +303,88 @@ impl GridBuilder {
         self
     }
 
+    /// Should the most recently allocated row stick to the top of the visible area? Default: `false`.
+    ///
+    /// Meant for grids shown inside a [`ScrollArea`](https://docs.rs/egui/latest/egui/containers/scroll_area/struct.ScrollArea.html).
+    /// Once scrolled past, a sticky row stays pinned to the top of the ui's clip rect (below any sticky rows before it) until the end of the grid is reached,
+    /// and the cells of other rows are clipped so they scroll underneath it.
+    ///
+    /// Does nothing unless at least one row has been allocated.
+    pub fn sticky(mut self, sticky: bool) -> Self {
+        let len = self.units.len();
+        if len > 0 {
+            self.units[len - 1].sticky = sticky;
+        }
+        self
+    }
+
+    /// Let the most recently allocated row collapse down to `size` (`0.0` to vanish entirely, or the height of a header strip to keep one showing).
+    ///
+    /// Rows are collapsed & expanded using [`Grid::collapse_row`], going by the order they were allocated in.
+    /// The change is animated, and the space a collapsing row frees up goes to any rows using [`Size::remainder`](https://docs.rs/egui_extras/latest/egui_extras/enum.Size.html#method.remainder).
+    /// The cells of a collapsing row are clipped, so their contents don't spill out.
+    ///
+    /// Does nothing unless at least one row has been allocated.
+    ///
+    /// ## Example
+    /// ```
+    /// # use egui_grid::GridBuilder;
+    /// # use egui_extras::Size;
+    /// # fn demo(ui: &mut egui::Ui) {
+    /// GridBuilder::new()
+    ///     // A panel which collapses down to its 24 point tall header
+    ///     .new_row(Size::exact(200.0)).cell(Size::remainder())
+    ///     .collapsible(24.0)
+    ///     .new_row(Size::remainder()).cell(Size::remainder())
+    ///     .show(ui, |mut grid| {
+    ///         let collapsed = grid.row_collapsed(0);
+    ///         let mut toggle = false;
+    ///         grid.cell(|ui| {
+    ///             toggle = ui.button("Details").clicked();
+    ///             ui.label("Only seen while expanded");
+    ///         });
+    ///         if toggle {
+    ///             grid.collapse_row(0, !collapsed);
+    ///         }
+    ///         grid.cell(|ui| {
+    ///             ui.label("Grows to fill the space left behind");
+    ///         });
+    ///     });
+    /// # }
+    /// ```
+    pub fn collapsible(mut self, size: f32) -> Self {
+        let len = self.units.len();
+        if len > 0 {
+            self.units[len - 1].collapse = Some(Collapse::new(size));
+        }
+        self
+    }
+
+    /// Should the most recently allocated row be part of the grid? Default: `true`.
+    ///
+    /// A hidden row is left out entirely, taking up no space and with its cells skipped by [`Grid::cell`].
+    /// Rows still count towards [`Grid::collapse_row`] as allocated, hidden or not.
+    ///
+    /// Does nothing unless at least one row has been allocated.
+    pub fn row_visible(mut self, visible: bool) -> Self {
+        let len = self.units.len();
+        if len > 0 {
+            self.units[len - 1].visible = visible;
+        }
+        self
+    }
+
+    /// Freeze the first `amount` cells of every row, keeping them pinned to the left side of the visible area
+    /// when the grid is scrolled horizontally. Default: `0`.
+    ///
+    /// Like [`Self::sticky`], the remaining cells of each row are clipped so they scroll underneath the frozen ones.
+    ///
+    /// This setting will not propagate to nested grids.
+    pub fn frozen_cells(mut self, amount: i32) -> Self {
+        self.frozen = amount.max(0) as usize;
+        self
+    }
+
     /// Add a cell to the most recently allocated row. Cells are represented left-to-right.
     /// Does nothing unless at least one row has been allocated.
     pub fn cell(mut self, size: Size) -> Self {
@@ -158,6 +404,9 @@ impl GridBuilder {
     ///
     /// ## Example
     /// ```
+    /// # use egui_grid::GridBuilder;
+    /// # use egui_extras::Size;
+    /// # use egui::Margin;
     /// let grid = GridBuilder::new()
     ///     .new_row(Size::remainder())
     ///     // This 'cell' will have a custom margin
@@ -189,6 +438,266 @@ impl GridBuilder {
         self
     }
 
+    /// Paint the most recently allocated cells with a [`CellFrame`] (or a plain [`Frame`](https://docs.rs/egui/latest/egui/containers/struct.Frame.html)).
+    ///
+    /// Behavior matches [`Self::with_margin`]. Takes priority over [`Self::row_frame`] & [`Self::frame`].
+    pub fn with_frame(mut self, frame: impl Into<CellFrame>) -> Self {
+        let frame = frame.into();
+        for item in self.creation_cache.iter() {
+            self.units[item.0].cells[item.1].frame = Some(frame);
+        }
+        self
+    }
+
+    /// Give the most recently allocated cells a [`Sense`](https://docs.rs/egui/latest/egui/struct.Sense.html), making them interactive.
+    /// The [`Response`](https://docs.rs/egui/latest/egui/struct.Response.html) is returned by [`Grid::cell`].
+    ///
+    /// Behavior matches [`Self::with_margin`]. Default: `Sense::hover()`.
+    pub fn with_sense(mut self, sense: Sense) -> Self {
+        for item in self.creation_cache.iter() {
+            self.units[item.0].cells[item.1].sense = sense;
+        }
+        self
+    }
+
+    /// Let the most recently allocated cells collapse down to `size`, collapsed & expanded using [`Grid::collapse_cell`].
+    ///
+    /// Behavior matches [`Self::with_margin`], with collapsing otherwise matching [`Self::collapsible`]. The cells of flows & masonries can't collapse.
+    pub fn with_collapsible(mut self, size: f32) -> Self {
+        for item in self.creation_cache.iter() {
+            self.units[item.0].cells[item.1].collapse = Some(Collapse::new(size));
+        }
+        self
+    }
+
+    /// Should the most recently allocated cells be part of the grid? Default: `true`.
+    ///
+    /// Hidden cells are left out entirely, taking up no space and being skipped by [`Grid::cell`], unlike a cell filled using [`Grid::empty`].
+    /// [`Grid::named`] does nothing for a hidden cell, so named cells can be filled the same way whether they're shown or not.
+    ///
+    /// Behavior matches [`Self::with_margin`]. Using this after [`Self::new_flow`] hides the entire flow.
+    ///
+    /// ## Example
+    /// ```
+    /// # use egui_grid::GridBuilder;
+    /// # use egui_extras::Size;
+    /// # fn demo(ui: &mut egui::Ui, show_sidebar: bool) {
+    /// GridBuilder::new()
+    ///     .new_row(Size::remainder())
+    ///     .cell(Size::exact(160.0)) .named("sidebar") .visible(show_sidebar)
+    ///     // Takes up the space of the sidebar while it's hidden
+    ///     .cell(Size::remainder())
+    ///     .show(ui, |mut grid| {
+    ///         grid.named("sidebar", |ui| {
+    ///             ui.label("Sidebar");
+    ///         });
+    ///         grid.cell(|ui| {
+    ///             ui.label("Content");
+    ///         });
+    ///     });
+    /// # }
+    /// ```
+    pub fn visible(mut self, visible: bool) -> Self {
+        for item in self.creation_cache.iter() {
+            self.units[item.0].cells[item.1].visible = visible;
+        }
+        self
+    }
+
+    /// Only allocate whatever `build` allocates when `condition` is `true`, without breaking up the chain of calls.
+    ///
+    /// Unlike [`Self::visible`] & [`Self::row_visible`], anything skipped this way was never allocated, so the rows after it are counted differently.
+    ///
+    /// ## Example
+    /// ```
+    /// # use egui_grid::GridBuilder;
+    /// # use egui_extras::Size;
+    /// # fn demo(wide: bool) {
+    /// let grid = GridBuilder::new()
+    ///     .new_row(Size::remainder())
+    ///     .cell(Size::remainder())
+    ///     // A second column, but only when there's room for it
+    ///     .when(wide, |grid| grid.cell(Size::remainder()));
+    /// # }
+    /// ```
+    pub fn when(self, condition: bool, build: impl FnOnce(Self) -> Self) -> Self {
+        if condition {
+            build(self)
+        } else {
+            self
+        }
+    }
+
+    /// Paint every cell of the most recently allocated row with a [`CellFrame`] (or a plain [`Frame`](https://docs.rs/egui/latest/egui/containers/struct.Frame.html)).
+    /// This will work regardless if the row has been populated with cells or not.
+    ///
+    /// Takes priority over [`Self::frame`], but not [`Self::with_frame`]. Does nothing unless at least one row has been allocated.
+    pub fn row_frame(mut self, frame: impl Into<CellFrame>) -> Self {
+        let len = self.units.len();
+        if len > 0 {
+            self.units[len - 1].frame = Some(frame.into());
+        }
+        self
+    }
+
+    /// Paint every cell of the grid with a [`CellFrame`] (or a plain [`Frame`](https://docs.rs/egui/latest/egui/containers/struct.Frame.html)).
+    /// Frames are painted before the contents of a cell, which are padded by the frame's inner margin.
+    ///
+    /// Overridden by [`Self::row_frame`] & [`Self::with_frame`]. This setting will not propagate to nested grids.
+    pub fn frame(mut self, frame: impl Into<CellFrame>) -> Self {
+        self.frame = Some(frame.into());
+        self
+    }
+
+    /// Paint [`Separators`] in the spacing between rows & cells, as well as an optional border around the grid.
+    ///
+    /// Separators follow the grid when [`Self::rows_as_columns`] is used, with lines between rows becoming lines between columns.
+    /// This setting will not propagate to nested grids, which can be given separators of their own.
+    pub fn separators(mut self, separators: Separators) -> Self {
+        self.separators = Some(separators);
+        self
+    }
+
+    /// Paint the background of every other row, using the ui's [`faint_bg_color`](https://docs.rs/egui/latest/egui/style/struct.Visuals.html#structfield.faint_bg_color). Default: `false`.
+    ///
+    /// Backgrounds span the entire grid, covering the spacing between cells. The cells of nested grids belong to the row of the cell holding them.
+    pub fn striped(mut self, striped: bool) -> Self {
+        self.striped = striped;
+        self
+    }
+
+    /// Highlight the background of whichever row is being hovered. Default: `false`.
+    ///
+    /// Behavior otherwise matches [`Self::striped`].
+    pub fn highlight_rows(mut self, highlight: bool) -> Self {
+        self.highlight_rows = highlight;
+        self
+    }
+
+    /// Let the user select cells by clicking them. Selected cells are painted using the ui's [`selection`](https://docs.rs/egui/latest/egui/style/struct.Visuals.html#structfield.selection) color.
+    ///
+    /// Cells are selected by their index in the order used by [`Grid::cell`] (or by row, see [`Self::select_rows`]),
+    /// but remembered by their id, so cells given a name using [`Self::named`] stay selected wherever they end up.
+    /// The selection is remembered between frames (see [`Self::id_source`]), and is returned by [`Self::show`] along with whether the user changed it.
+    ///
+    /// ## Example
+    /// ```
+    /// # use egui_grid::{GridBuilder, SelectionMode};
+    /// # use egui_extras::Size;
+    /// # fn demo(ui: &mut egui::Ui, files: &[&str]) {
+    /// let response = GridBuilder::new()
+    ///     .new_flow(Size::exact(24.0), 120.0, 240.0, files.len() as i32)
+    ///     .selection(SelectionMode::Multi)
+    ///     .show(ui, |mut grid| {
+    ///         for file in files {
+    ///             grid.cell(|ui| {
+    ///                 ui.label(*file);
+    ///             });
+    ///         }
+    ///     });
+    ///
+    /// if response.selection_changed {
+    ///     let selected: Vec<&str> = response.selected.iter().map(|index| files[*index]).collect();
+    /// }
+    /// # }
+    /// ```
+    pub fn selection(mut self, mode: SelectionMode) -> Self {
+        self.selection = Some(mode);
+        self
+    }
+
+    /// Should entire rows be selected instead of single cells? Default: `false`.
+    ///
+    /// Rows are selected by their index, as given by [`Grid::row`], & remembered by the id of their first cell. Clicking any cell within a row selects it.
+    pub fn select_rows(mut self, rows: bool) -> Self {
+        self.select_rows = rows;
+        self
+    }
+
+    /// Let the user move between cells using the arrow keys. Default: `false`.
+    ///
+    /// Cells can be focused by clicking them or using tab, after which the arrow keys move focus to the nearest cell in that direction,
+    /// going by where cells are on screen (nested grids and [`Self::rows_as_columns`] included).
+    /// The focused cell is outlined, and can be activated by pressing enter or space, which is returned by [`Self::show`].
+    pub fn keyboard_navigation(mut self, navigation: bool) -> Self {
+        self.keyboard_navigation = navigation;
+        self
+    }
+
+    /// Let the user rearrange cells (or rows) by dragging them onto one another.
+    ///
+    /// While dragging, the cell (or row) being dragged and where it'd be dropped are outlined.
+    /// Once dropped, the [`Move`](crate::Move) is returned by [`Self::show`]. The grid itself doesn't change, so it's up to the app to rearrange whatever it's showing.
+    ///
+    /// ## Example
+    /// ```
+    /// # use egui_grid::{GridBuilder, ReorderMode};
+    /// # use egui_extras::Size;
+    /// # fn demo(ui: &mut egui::Ui, tiles: &mut Vec<String>) {
+    /// let response = GridBuilder::new()
+    ///     .new_flow(Size::exact(120.0), 160.0, 240.0, tiles.len() as i32)
+    ///     .reorder(ReorderMode::Cells)
+    ///     .show(ui, |mut grid| {
+    ///         for tile in tiles.iter() {
+    ///             grid.cell(|ui| {
+    ///                 ui.label(tile);
+    ///             });
+    ///         }
+    ///     });
+    ///
+    /// if let Some(moved) = response.moved {
+    ///     moved.apply(tiles);
+    /// }
+    /// # }
+    /// ```
+    pub fn reorder(mut self, mode: ReorderMode) -> Self {
+        self.reorder = Some(mode);
+        self
+    }
+
+    /// Animate cells moving & resizing, having them take `duration` seconds to get to where they're going. Default: off.
+    ///
+    /// Each cell picks up from wherever it was the frame before, so changes to sizes, [`Self::collapsible`] rows and layouts switched by [`Self::breakpoint`] all animate.
+    /// Cells are told apart by where they were declared (or their name, see [`Self::named`]), so they're followed as flows wrap differently & cells around them are hidden,
+    /// with the cells of nested grids animated along with the rest.
+    /// Cells of [`Self::sticky`] rows & [`Self::frozen_cells`] aren't animated, so they stay pinned while scrolling.
+    pub fn animate(mut self, duration: f32) -> Self {
+        self.animate = Some(duration.max(0.));
+        self
+    }
+
+    /// Paint an overlay over the grid for working out why a layout isn't behaving. Default: `false`.
+    ///
+    /// Outlines each cell, with its margins painted orange & the spacing around it magenta.
+    /// Each cell is labeled with its index in the order used by [`Grid::cell`], its name, and the sizes of the row & cell it came from.
+    /// The overlay is painted above everything else, cells of nested grids included.
+    ///
+    /// See [`Self::debug_all`] to paint the overlay over every grid at once.
+    pub fn debug(mut self, debug: bool) -> Self {
+        self.debug = debug;
+        self
+    }
+
+    /// Paint the overlay of [`Self::debug`] over every grid, until turned off again. Remembered in egui's memory.
+    ///
+    /// ## Example
+    /// ```
+    /// # use egui_grid::GridBuilder;
+    /// # fn demo(ui: &mut egui::Ui) {
+    /// if ui.input(|input| input.key_pressed(egui::Key::F12)) {
+    ///     GridBuilder::debug_all(ui.ctx(), !GridBuilder::is_debugging_all(ui.ctx()));
+    /// }
+    /// # }
+    /// ```
+    pub fn debug_all(ctx: &Context, debug: bool) {
+        ctx.data_mut(|data| data.insert_temp(crate::debug::key(), debug));
+    }
+
+    /// Whether the overlay of [`Self::debug`] is being painted over every grid, see [`Self::debug_all`].
+    pub fn is_debugging_all(ctx: &Context) -> bool {
+        crate::debug::enabled(ctx)
+    }
+
     /// All cells allocated going forward will use this [`Layout`](https://docs.rs/egui/latest/egui/struct.Layout.html) as default.
     /// *Does not effect previously allocated cells*.
     ///
@@ -203,6 +712,9 @@ impl GridBuilder {
     ///
     /// ## Example
     /// ```
+    /// # use egui_grid::GridBuilder;
+    /// # use egui_extras::Size;
+    /// # fn demo(ui: &mut egui::Ui) {
     /// // A grid of 4 cells which all take up equal space
     /// let nested_grid = GridBuilder::new()
     ///     .new_row(Size::remainder()).cells(Size::remainder(), 2)
@@ -231,6 +743,7 @@ impl GridBuilder {
     ///             ui.label("Nested cell bottom-right");
     ///         });
     ///     });
+    /// # }
     /// ```
     pub fn nest(mut self, grid: GridBuilder) -> Self {
         let len = self.units.len();
@@ -244,6 +757,98 @@ impl GridBuilder {
         self
     }
 
+    /// Give the most recently allocated cell a name, which it can then be filled by using [`Grid::named`].
+    /// Does nothing in the absence of any rows or the most recently allocated row being absent of any cells.
+    ///
+    /// The contents of a named cell keep their state wherever the cell ends up, even when moved in or out of a nested grid, making names the way to go when using [`Self::breakpoint`].
+    /// Names should be unique across the grid along with every grid nested within it.
+    /// If a name is given to a cell holding a nested grid, the name will go unused.
+    pub fn named(mut self, name: impl Into<String>) -> Self {
+        let len = self.units.len();
+        if len > 0 {
+            let cell_len = self.units[len - 1].cells.len();
+            if cell_len > 0 {
+                self.units[len - 1].cells[cell_len - 1].name = Some(name.into());
+            }
+        }
+        self
+    }
+
+    /// Mirror the grid horizontally when it's shown, having the first cell of a row start at the right. Default: `false`.
+    ///
+    /// Mirroring happens after everything else, so [`Self::align`] and [`Self::rows_as_columns`] still act as they would before being mirrored.
+    /// Nested grids are mirrored along with the cell holding them (mirroring a nested grid as well will undo it).
+    ///
+    /// Only the placement of cells is mirrored, use [`Self::right_to_left`] to mirror the layout of their contents too.
+    pub fn mirror_horizontally(mut self, mirror: bool) -> Self {
+        self.mirror_x = mirror;
+        self
+    }
+
+    /// Mirror the grid vertically when it's shown, having the first row start at the bottom. Default: `false`.
+    ///
+    /// Behavior otherwise matches [`Self::mirror_horizontally`].
+    pub fn mirror_vertically(mut self, mirror: bool) -> Self {
+        self.mirror_y = mirror;
+        self
+    }
+
+    /// Lay the grid out for right-to-left languages. Default: `false`.
+    ///
+    /// Along with mirroring the grid horizontally (see [`Self::mirror_horizontally`]), the [`Layout`](https://docs.rs/egui/latest/egui/struct.Layout.html) of every cell is mirrored too,
+    /// so `Layout::left_to_right` becomes `Layout::right_to_left` and cells laid out top-down align to the right instead of the left.
+    /// [`Align::Min`](https://docs.rs/egui/latest/egui/enum.Align.html) for rows now means the right side of the row.
+    ///
+    /// Since nested grids are mirrored along with the cell holding them, this only needs to be set on the outermost grid.
+    pub fn right_to_left(mut self, rtl: bool) -> Self {
+        self.rtl = rtl;
+        self
+    }
+
+    /// Register a different layout to be used in place of this one when the space available to the grid meets the given [`Breakpoint`].
+    ///
+    /// Breakpoints are checked in the order they were registered, with the first one met being used. If none are met this layout is used.
+    /// This works for nested grids too, which are checked against the space of the cell holding them.
+    ///
+    /// Since layouts can differ in the order and amount of cells they have, their cells are best filled by name using [`Self::named`] and [`Grid::named`].
+    /// Everything else is taken from whichever layout is in use too, such as [`Self::selection`] & [`Self::animate`], aside from [`Self::id_source`].
+    ///
+    /// ## Example
+    /// ```
+    /// # use egui_grid::{Breakpoint, GridBuilder};
+    /// # use egui_extras::Size;
+    /// # fn demo(ui: &mut egui::Ui) {
+    /// // Three columns side by side when there's room for them
+    /// let wide = GridBuilder::new()
+    ///     .new_row(Size::remainder())
+    ///     .cell(Size::exact(200.0)) .named("nav")
+    ///     .cell(Size::remainder()) .named("content")
+    ///     .cell(Size::exact(200.0)) .named("details");
+    ///
+    /// // Otherwise stacked on top of each other
+    /// GridBuilder::new()
+    ///     .new_row(Size::exact(40.0)) .cell(Size::remainder()) .named("nav")
+    ///     .new_row(Size::remainder()) .cell(Size::remainder()) .named("content")
+    ///     .new_row(Size::exact(120.0)) .cell(Size::remainder()) .named("details")
+    ///     .breakpoint(Breakpoint::MinWidth(900.0), wide)
+    ///     .show(ui, |mut grid| {
+    ///         grid.named("nav", |ui| {
+    ///             ui.label("Navigation");
+    ///         });
+    ///         grid.named("content", |ui| {
+    ///             ui.label("Content");
+    ///         });
+    ///         grid.named("details", |ui| {
+    ///             ui.label("Details");
+    ///         });
+    ///     });
+    /// # }
+    /// ```
+    pub fn breakpoint(mut self, breakpoint: Breakpoint, layout: GridBuilder) -> Self {
+        self.breakpoints.push((breakpoint, layout));
+        self
+    }
+
     /// Nest a grid at a given row in a given cell. Nothing will happen if a cell doesn't exist at the given coordinates.
     pub fn nest_at(mut self, row: i32, cell: i32, grid: GridBuilder) -> Self {
         let u_row = row as usize;
@@ -257,21 +862,116 @@ impl GridBuilder {
     /// Rows are positioned top-to-bottom spanning horizontally, and cells within rows left-to-right.
     ///
     /// The cells of a nested grid will be represented in place of the cell that held it.
-    pub fn show(self, ui: &mut Ui, grid: impl FnOnce(Grid)) -> Response {
+    pub fn show(mut self, ui: &mut Ui, grid: impl FnOnce(Grid)) -> GridResponse {
         //if self.use_default_spacing { self.spacing = ui.style_mut().spacing.item_spacing;  }
         let allocated_space = ui.available_rect_before_wrap();
-        let pure_cells = self.to_real_cells(allocated_space, ui.style().spacing.item_spacing);
-        let mut bounds = Pos2::new(0., 0.);
+        let id = match self.id_source {
+            Some(id_source) => ui.make_persistent_id(id_source),
+            None => {
+                // Like egui's own widgets, grids without an id_source go by where they're shown
+                let id = ui.next_auto_id();
+                ui.skip_ahead_auto_ids(1);
+                id
+            }
+        };
+        let env = Env {
+            ctx: Some(ui.ctx().clone()),
+            spacing: ui.style().spacing.item_spacing,
+        };
+        let inspecting = self.inspect(ui.ctx(), id, allocated_space);
+        let mut computed = self.to_real_cells(allocated_space, ui.clip_rect(), id, &env);
+        // Everything else is set by whichever layout is in use
+        let active = self.active(allocated_space);
+        if let Some(duration) = active.animate {
+            for cell in computed.cells.iter_mut() {
+                let duration = if cell.pinned { 0. } else { duration };
+                cell.rect = transition(ui.ctx(), cell.id, cell.rect, allocated_space.min, duration);
+            }
+        }
+        if inspecting {
+            self.record(ui.ctx(), id, allocated_space, &computed.cells);
+        }
+        let mut output = Output {
+            selection_mode: active.selection,
+            select_rows: active.select_rows,
+            keyboard_navigation: active.keyboard_navigation,
+            reorder: active.reorder,
+            ..Default::default()
+        };
+        if active.keyboard_navigation {
+            navigate(ui, &computed.cells);
+        }
+        if active.selection.is_some() {
+            output.selection = Selection::load(ui.ctx(), id.with("selection"));
+            if !ui.input(|input| input.pointer.primary_down()) {
+                output.selection.dragging = false;
+            }
+            // Rows are kept by the id of their first cell
+            output.selection_keys = if active.select_rows {
+                let mut keys: Vec<Id> = (0..computed.rows.len())
+                    .map(|row| id.with(("row", row)))
+                    .collect();
+                for cell in computed.cells.iter().rev() {
+                    keys[cell.row] = cell.id;
+                }
+                keys
+            } else {
+                computed.cells.iter().map(|cell| cell.id).collect()
+            };
+        }
+        output.collapsed = Collapsed::load(ui.ctx(), id.with("collapsed"));
 
-        grid(Grid::new(ui, pure_cells, &mut bounds));
+        for (row_index, rect) in computed.rows.iter().enumerate() {
+            let selected = output
+                .selection_keys
+                .get(row_index)
+                .is_some_and(|key| output.selection.selected.contains(key));
+            let fill = if active.select_rows && selected {
+                ui.visuals().selection.bg_fill
+            } else if computed.highlight_rows && ui.rect_contains_pointer(*rect) {
+                ui.visuals().widgets.hovered.bg_fill
+            } else if computed.striped && row_index % 2 == 1 {
+                ui.visuals().faint_bg_color
+            } else {
+                continue;
+            };
+            if rect.is_positive() {
+                ui.painter().rect_filled(*rect, 0., fill);
+            }
+        }
+        for (line, stroke) in computed.lines {
+            ui.painter().line_segment([line.min, line.max], stroke);
+        }
+        if active.debug || crate::debug::enabled(ui.ctx()) {
+            crate::debug::paint(ui, &computed.cells, &computed.rows);
+        }
+        let cell_rects: Vec<Rect> = computed.cells.iter().map(|cell| cell.rect()).collect();
+        grid(Grid::new(ui, computed.cells, &mut output));
+        let moved = output.finish_drag(ui, &cell_rects, &computed.rows);
 
-        ui.allocate_rect(
+        let response = ui.allocate_rect(
             Rect {
                 min: allocated_space.min,
-                max: bounds,
+                max: output.bounds,
             },
             Sense::hover(),
-        )
+        );
+
+        let selected = output.selection.indexes(&output.selection_keys);
+        if active.selection.is_some() {
+            output.selection.store(ui.ctx(), id.with("selection"));
+        }
+        if output.collapsed.changed {
+            output.collapsed.store(ui.ctx(), id.with("collapsed"));
+            ui.ctx().request_repaint();
+        }
+        GridResponse {
+            response,
+            selected,
+            selection_changed: output.selection_changed,
+            activated: output.activated,
+            moved,
+        }
     }
 
     /// Setting to `true` will result in rows acting as columns when [`Self::show`] is called (with the cells within being represented top-to-bottom instead of left-to-right).
@@ -284,14 +984,29 @@ impl GridBuilder {
     ///
     /// Default: `false`.
     pub fn rows_as_columns(mut self, vertical: bool) -> Self {
-        self.row_as_col = vertical;
+        self.orientation = if vertical {
+            Orientation::Columns
+        } else {
+            Orientation::Rows
+        };
+        self
+    }
+
+    /// Set the [`Orientation`] of the grid, a more flexible version of [`Self::rows_as_columns`].
+    ///
+    /// Using [`Orientation::Auto`] lets the same grid work in both landscape & portrait spaces,
+    /// with rows acting as columns whenever the space available to the grid is taller than it is wide.
+    ///
+    /// Default: [`Orientation::Rows`].
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
         self
     }
 
     // General purpose method for adding cells
     fn add_cells(&mut self, size: Size, amount: i32, margin: Margin) {
         let len = self.units.len();
-        if len > 0 {
+        if len > 0 && self.units[len - 1].flow.is_none() {
             let cel_len = self.units[len - 1].cells.len();
             self.creation_cache = Vec::new();
             for c in 1..=amount {
@@ -305,13 +1020,67 @@ impl GridBuilder {
     }
 
     // Turn sizes into rectangles and build PureCells
-    fn to_real_cells(&self, whole_rect: Rect, def_spacing: Vec2) -> Vec<PureCell> {
+    // `viewport` is the visible area, used to pin sticky rows and frozen cells
+    pub(crate) fn to_real_cells(
+        &self,
+        whole_rect: Rect,
+        viewport: Rect,
+        id: Id,
+        env: &Env,
+    ) -> Computed {
+        self.to_real_cells_within(whole_rect, viewport, id, id, env)
+    }
+
+    // The layout used for the given space, going by breakpoints
+    pub(crate) fn active(&self, rect: Rect) -> &GridBuilder {
+        match self
+            .breakpoints
+            .iter()
+            .find(|(breakpoint, _)| breakpoint.matches(rect))
+        {
+            Some((_, layout)) => layout.active(rect),
+            None => self,
+        }
+    }
+
+    pub(crate) fn active_mut(&mut self, rect: Rect) -> &mut GridBuilder {
+        match self
+            .breakpoints
+            .iter()
+            .position(|(breakpoint, _)| breakpoint.matches(rect))
+        {
+            Some(index) => self.breakpoints[index].1.active_mut(rect),
+            None => self,
+        }
+    }
+
+    // Same as to_real_cells, for a grid nested somewhere within the grid given `root` as its id.
+    // Named cells are keyed by the root's id, so they keep it between being nested & not
+    fn to_real_cells_within(
+        &self,
+        whole_rect: Rect,
+        viewport: Rect,
+        id: Id,
+        root: Id,
+        env: &Env,
+    ) -> Computed {
+        // Hand things off to a different layout if a breakpoint is met
+        if let Some((_, layout)) = self
+            .breakpoints
+            .iter()
+            .find(|(breakpoint, _)| breakpoint.matches(whole_rect))
+        {
+            return layout.to_real_cells_within(whole_rect, viewport, id, root, env);
+        }
+
+        let mut computed = Computed::default();
         let mut cells_final = Vec::new();
 
         // For row_as_col functionality
+        let row_as_col = self.orientation.is_columns(whole_rect, id, env);
         let whole_h;
         let whole_w;
-        if self.row_as_col {
+        if row_as_col {
             (whole_w, whole_h) = (whole_rect.height(), whole_rect.width());
         } else {
             (whole_h, whole_w) = (whole_rect.height(), whole_rect.width());
@@ -319,50 +1088,167 @@ impl GridBuilder {
 
         // Spacing
         let spacing = if self.use_default_spacing {
-            swap_spacing(def_spacing, self.row_as_col)
+            swap_spacing(env.spacing, row_as_col)
         } else {
-            swap_spacing(self.spacing, self.row_as_col)
+            swap_spacing(self.spacing, row_as_col)
         };
 
-        let row_lengths = row_set_as_f32(&self.units, &spacing.y, &whole_h);
+        // Which rows & cells have been collapsed
+        let collapsible = self.units.iter().any(Row::collapsible);
+        let collapsed = match (&env.ctx, collapsible) {
+            (Some(ctx), true) => Collapsed::load(ctx, id.with("collapsed")),
+            _ => Collapsed::default(),
+        };
+
+        // Leave out anything hidden, wrap any flows into regular rows, place the cells of any masonries & animate anything collapsing
+        // Along with which row & cells were declared to become each row, as that's lost along the way,
+        // & what each cell is keyed by (its place within a flow, as every cell of a flow was declared as the same one)
+        let (units, sources) = if collapsible || self.units.iter().any(Row::needs_resolving) {
+            let (units, sources): (Vec<Row>, Vec<_>) = self
+                .units
+                .iter()
+                .enumerate()
+                .filter(|(_, row)| row.visible)
+                .flat_map(|(index, row)| {
+                    let mut row = row.collapsing(index, &collapsed, id, env);
+                    let cells: Vec<usize> = (0..row.cells.len())
+                        .filter(|cell| row.cells[*cell].visible)
+                        .collect();
+                    row.cells.retain(|cell| cell.visible);
+                    let flow = row.flow.is_some();
+                    let mut placed = 0;
+                    row.resolve(whole_w, spacing, id.with(index), env)
+                        .into_iter()
+                        .map(move |resolved| {
+                            // Flows are filled with copies of their first cell
+                            let (cells, keys) = if flow {
+                                let amount = resolved.cells.len();
+                                placed += amount;
+                                (vec![0; amount], (placed - amount..placed).collect())
+                            } else {
+                                (cells.clone(), cells.clone())
+                            };
+                            (resolved, (index, cells, keys))
+                        })
+                })
+                .unzip();
+            (Cow::Owned(units), Some(sources))
+        } else {
+            (Cow::Borrowed(&self.units), None)
+        };
+        let source = |row: usize, cell: usize| match &sources {
+            Some(sources) => vec![sources[row].0, sources[row].1[cell]],
+            None => vec![row, cell],
+        };
+        // Unnamed cells are keyed by where they were declared, so they keep their id while anything around them is resolved differently
+        // The sizes a row & cell were declared with, as resolving flows & masonries replaces them
+        let declared = |row: usize, cell: usize| match &sources {
+            Some(sources) => {
+                let declared = &self.units[sources[row].0];
+                (declared.size, declared.cells[sources[row].1[cell]].size)
+            }
+            None => (self.units[row].size, self.units[row].cells[cell].size),
+        };
+        let key = |row: usize, cell: usize| match &sources {
+            Some(sources) => (sources[row].0, sources[row].2[cell]),
+            None => (row, cell),
+        };
+
+        let row_lengths = row_set_as_f32(&units, &spacing.y, &whole_h);
+
+        // Sticky rows & frozen cells are worked out in the same space as the rows, so the viewport has to be mirrored & reflected too
+        let mirror_x = self.mirror_x || self.rtl;
+        let viewport = mirror(viewport, whole_rect, mirror_x, self.mirror_y);
+        let screen_viewport = viewport;
+        let viewport = if row_as_col {
+            reflect(viewport, whole_rect.min)
+        } else {
+            viewport
+        };
+        let (sticky_offsets, covered_y) =
+            sticky_offsets(&units, &row_lengths, spacing.y, whole_rect.min.y, viewport);
+        let frozen_offset = (viewport.min.x - whole_rect.min.x).max(0.);
 
         let mut pointer2d = Pos2::new(whole_rect.min.x, whole_rect.min.y);
 
-        for (row_index, row) in self.units.iter().enumerate() {
-            // Get cell sizes
-            let cell_lengths = cell_set_as_f32(&row.cells, &spacing.x, &whole_w);
+        // Each row's rect along with the rects of its cells, for the separators
+        let mut row_rects = Vec::new();
 
-            // sum of the lengths + spacing
-            let mut length_sum = -spacing.x; // minus spacing to counter balance the extra spacing added at the end of the for loop
-            for length in cell_lengths.iter() {
-                length_sum += length + spacing.x;
-            }
-            // apply align offset
-            let grand_offset: f32 = {
-                match &row.align {
-                    Align::Min => 0.,
-                    Align::Center => (whole_w - length_sum) * 0.5,
-                    Align::Max => whole_w - length_sum,
-                }
-            };
-            pointer2d.x += grand_offset;
+        for (row_index, row) in units.iter().enumerate() {
+            let slots = row.place(pointer2d, whole_w, row_lengths[row_index], spacing);
+            let row_x = slots
+                .iter()
+                .fold(Rect::NOTHING, |area, slot| area.union(*slot))
+                .x_range();
+            // Frozen cells can't be pushed past the end of their row
+            let row_end = slots
+                .iter()
+                .fold(pointer2d.x, |end, slot| end.max(slot.max.x));
+            let frozen_end = slots
+                .iter()
+                .take(self.frozen)
+                .fold(pointer2d.x, |end, slot| end.max(slot.max.x));
+            let row_frozen_offset = frozen_offset.min(row_end - frozen_end).max(0.);
+
+            row_rects.push(SeparatorRow {
+                rect: Rect::from_x_y_ranges(
+                    if slots.is_empty() {
+                        Rangef::point(pointer2d.x)
+                    } else {
+                        row_x
+                    },
+                    pointer2d.y..=pointer2d.y + row_lengths[row_index],
+                ),
+                cells: if row.masonry.is_some() {
+                    Vec::new()
+                } else {
+                    slots.clone()
+                },
+                sticky: row.sticky,
+                offset: sticky_offsets[row_index],
+                frozen: self.frozen,
+                frozen_offset: row_frozen_offset,
+            });
+
+            // The right-most edge of this row's frozen cells, anything not frozen gets clipped before it
+            let mut covered_x = None;
 
             for (cell_index, cell) in row.cells.iter().enumerate() {
+                let frozen = cell_index < self.frozen;
+                let collapsing = row.is_collapsing() || Collapse::is_active(cell.collapse);
+
                 // Build the rect
-                let mut rect = Rect {
-                    min: pointer2d,
-                    max: Pos2::new(
-                        pointer2d.x + cell_lengths[cell_index],
-                        pointer2d.y + row_lengths[row_index],
-                    ),
-                };
+                let mut rect = slots[cell_index];
+
+                // Pin sticky rows & frozen cells
+                let mut visible = None;
+                rect = rect.translate(Vec2::new(0., sticky_offsets[row_index]));
+                if !row.sticky {
+                    if let Some(y) = covered_y {
+                        visible = Some(Rect::from_min_max(
+                            Pos2::new(viewport.min.x, y),
+                            viewport.max,
+                        ));
+                    }
+                }
+                if frozen {
+                    rect = rect.translate(Vec2::new(row_frozen_offset, 0.));
+                    if row_frozen_offset > 0. {
+                        covered_x = Some(rect.max.x);
+                    }
+                } else if let Some(x) = covered_x {
+                    let area = visible.unwrap_or(viewport);
+                    visible = Some(Rect::from_min_max(Pos2::new(x, area.min.y), area.max));
+                }
 
                 // Apply verticality
-                if self.row_as_col {
+                if row_as_col {
                     rect = reflect(rect, whole_rect.min);
+                    visible = visible.map(|area| reflect(area, whole_rect.min));
                 }
 
                 // Apply margins
+                let outer = rect;
                 let margin = &(row.cells[cell_index].margin);
                 rect.min.x += margin.left;
                 rect.min.y += margin.top;
@@ -372,46 +1258,464 @@ impl GridBuilder {
                 // Check and handle nested grids
                 match &row.cells[cell_index].group {
                     Option::Some(grid) => {
-                        cells_final.extend(grid.to_real_cells(rect, def_spacing));
+                        let mut nested = grid.to_real_cells_within(
+                            rect,
+                            visible.unwrap_or(screen_viewport),
+                            id.with(key(row_index, cell_index)),
+                            root,
+                            env,
+                        );
+                        let path = source(row_index, cell_index);
+                        for nested_cell in nested.cells.iter_mut() {
+                            nested_cell.path.splice(0..0, path.iter().copied());
+                            nested_cell.row = row_index;
+                            nested_cell.depth += 1;
+                            nested_cell.pinned |= row.sticky || frozen;
+                            if let Some(area) = visible {
+                                nested_cell.restrict(area);
+                            }
+                            if collapsing {
+                                nested_cell.restrict(rect);
+                            }
+                        }
+                        cells_final.extend(nested.cells);
+                        // Nested lines get covered by sticky rows & frozen cells just like nested cells do
+                        for (mut line, stroke) in nested.lines {
+                            if let Some(area) = visible {
+                                line = line.intersect(area);
+                            }
+                            if collapsing {
+                                line = line.intersect(rect);
+                            }
+                            if line.min.x <= line.max.x && line.min.y <= line.max.y {
+                                computed.lines.push((line, stroke));
+                            }
+                        }
+                        computed.groups.push((outer, rect, 0));
+                        computed.groups.extend(
+                            nested
+                                .groups
+                                .into_iter()
+                                .map(|(outer, rect, depth)| (outer, rect, depth + 1)),
+                        );
                     }
                     Option::None => {
-                        cells_final.push(PureCell::new(cell.get_layout(), self.clip, rect));
+                        let mut pure =
+                            PureCell::new(cell.get_layout(), self.clip || collapsing, rect);
+                        pure.visible = visible;
+                        pure.outer = outer;
+                        pure.sizes = declared(row_index, cell_index);
+                        pure.path = source(row_index, cell_index);
+                        pure.row = row_index;
+                        pure.pinned = row.sticky || frozen;
+                        pure.id = match &cell.name {
+                            Some(name) => root.with(name),
+                            None => id.with(key(row_index, cell_index)),
+                        };
+                        pure.name = cell.name.clone();
+                        pure.sense = cell.sense;
+                        pure.frame = cell.frame.or(row.frame).or(self.frame);
+                        pure.measure = row
+                            .masonry
+                            .as_ref()
+                            .and_then(|masonry| masonry.measure[cell_index]);
+                        cells_final.push(pure);
                     }
                 }
-
-                // Update indexes
-                pointer2d.x += cell_lengths[cell_index] + spacing.x;
             }
 
             // Update indexes
-            pointer2d.x = whole_rect.min.x;
             pointer2d.y += row_lengths[row_index] + spacing.y;
         }
 
-        cells_final
+        if let Some(separators) = &self.separators {
+            for mut line in separators.lines(&row_rects, covered_y) {
+                if row_as_col {
+                    line = reflect(line, whole_rect.min);
+                }
+                computed.lines.push((line, separators.stroke));
+            }
+        }
+
+        // The rect of each row spanning the entire grid, moved along with any sticky rows
+        {
+            let content_x = row_rects
+                .iter()
+                .fold(Rect::NOTHING, |content, row| content.union(row.rect))
+                .x_range();
+            for (row_index, row_rect) in row_rects.iter().enumerate() {
+                let mut rect = Rect::from_x_y_ranges(content_x, row_rect.rect.y_range())
+                    .translate(Vec2::new(0., sticky_offsets[row_index]));
+                if let (false, Some(y)) = (units[row_index].sticky, covered_y) {
+                    rect.min.y = rect.min.y.max(y);
+                }
+                if row_as_col {
+                    rect = reflect(rect, whole_rect.min);
+                }
+                computed.rows.push(rect);
+            }
+        }
+        computed.striped = self.striped;
+        computed.highlight_rows = self.highlight_rows;
+
+        // Mirror everything, nested grids included
+        if mirror_x || self.mirror_y {
+            let around = mirror_area(whole_rect, cells_final.iter().map(|cell| cell.rect));
+            for (line, _) in computed.lines.iter_mut() {
+                *line = mirror(*line, around, mirror_x, self.mirror_y);
+            }
+            for row in computed.rows.iter_mut() {
+                *row = mirror(*row, around, mirror_x, self.mirror_y);
+            }
+            for (outer, rect, _) in computed.groups.iter_mut() {
+                *outer = mirror(*outer, around, mirror_x, self.mirror_y);
+                *rect = mirror(*rect, around, mirror_x, self.mirror_y);
+            }
+            for cell in cells_final.iter_mut() {
+                cell.rect = mirror(cell.rect, around, mirror_x, self.mirror_y);
+                cell.outer = mirror(cell.outer, around, mirror_x, self.mirror_y);
+                cell.visible = cell
+                    .visible
+                    .map(|area| mirror(area, around, mirror_x, self.mirror_y));
+                if self.rtl {
+                    cell.layout = mirror_layout(cell.layout);
+                }
+            }
+        }
+
+        computed.cells = cells_final;
+        computed
+    }
+}
+
+// Everything worked out by a grid when it's shown
+#[derive(Default)]
+pub(crate) struct Computed {
+    pub cells: Vec<PureCell>,
+    // Separator lines, each going from the min to the max of its rect
+    pub lines: Vec<(Rect, Stroke)>,
+    // The backgrounds of each row
+    pub rows: Vec<Rect>,
+    pub striped: bool,
+    pub highlight_rows: bool,
+    // The cells holding nested grids, with & without their margins, and how deeply they're nested
+    pub groups: Vec<(Rect, Rect, usize)>,
+}
+
+/// Which way the rows of a grid run, set with [`GridBuilder::orientation`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Orientation {
+    /// Rows are top-to-bottom, with the cells within them left-to-right.
+    Rows,
+    /// Rows act as columns, left-to-right, with the cells within them top-to-bottom. Same as [`GridBuilder::rows_as_columns`].
+    Columns,
+    /// Rows act as columns whenever the space available to the grid is taller than it is wide.
+    ///
+    /// To keep the grid from flickering between the two when the space is close to square, the space has to be
+    /// this much (as a fraction, e.g. `0.1` for 10%) taller than it is wide to switch to columns, and wider than it is tall to switch back.
+    Auto(f32),
+}
+
+impl Orientation {
+    // Whether rows should be acting as columns, remembering the last answer for Orientation::Auto
+    fn is_columns(&self, rect: Rect, id: Id, env: &Env) -> bool {
+        let hysteresis = match *self {
+            Orientation::Rows => return false,
+            Orientation::Columns => return true,
+            Orientation::Auto(hysteresis) => 1. + hysteresis.max(0.),
+        };
+
+        let key = id.with("orientation");
+        let previous = env
+            .ctx
+            .as_ref()
+            .and_then(|ctx| ctx.data(|data| data.get_temp::<bool>(key)));
+        let columns = match previous {
+            Some(false) => rect.height() > rect.width() * hysteresis,
+            Some(true) => rect.width() <= rect.height() * hysteresis,
+            None => rect.height() > rect.width(),
+        };
+        if previous != Some(columns) {
+            if let Some(ctx) = &env.ctx {
+                ctx.data_mut(|data| data.insert_temp(key, columns));
+            }
+        }
+        columns
     }
 }
 
+/// A condition on the space available to a grid, used to pick between layouts with [`GridBuilder::breakpoint`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Breakpoint {
+    /// At least this much width is available.
+    MinWidth(f32),
+    /// Less than this much width is available.
+    MaxWidth(f32),
+    /// At least this much height is available.
+    MinHeight(f32),
+    /// Less than this much height is available.
+    MaxHeight(f32),
+    /// The available width divided by the available height is at least this much.
+    MinAspect(f32),
+    /// The available width divided by the available height is less than this much.
+    MaxAspect(f32),
+}
+
+impl Breakpoint {
+    fn matches(&self, rect: Rect) -> bool {
+        match *self {
+            Breakpoint::MinWidth(width) => rect.width() >= width,
+            Breakpoint::MaxWidth(width) => rect.width() < width,
+            Breakpoint::MinHeight(height) => rect.height() >= height,
+            Breakpoint::MaxHeight(height) => rect.height() < height,
+            Breakpoint::MinAspect(aspect) => rect.aspect_ratio() >= aspect,
+            Breakpoint::MaxAspect(aspect) => rect.aspect_ratio() < aspect,
+        }
+    }
+}
+
+// What grids need from the ui they're shown in, shared with any nested grids
+pub(crate) struct Env {
+    pub ctx: Option<Context>,
+    pub spacing: Vec2,
+}
+
 // Represents a row of cells. Useless on it's own, must be given to a GridBuilder.
 #[derive(Clone)]
 pub(crate) struct Row {
     pub size: Size,
+    pub sticky: bool,
     cells: Vec<Cell>,
     align: Align,
+    flow: Option<Flow>,
+    masonry: Option<Masonry>,
+    frame: Option<CellFrame>,
+    pub collapse: Option<Collapse>,
+    pub visible: bool,
+}
+
+// The details of a flow, the row it belongs to holds a single cell which is used as a template
+#[derive(Clone)]
+pub(crate) struct Flow {
+    width: Rangef,
+    amount: usize,
+}
+
+// The details of a masonry, its cells are placed once the width of the grid is known
+#[derive(Clone)]
+pub(crate) struct Masonry {
+    columns: MasonryColumns,
+    // Where each cell sits relative to the top-left of the row
+    placed: Vec<Rect>,
+    // The ids that cells measured from their contents store their height under
+    measure: Vec<Option<Id>>,
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum MasonryColumns {
+    Fixed(usize),
+    Auto(f32),
+}
+
+impl Masonry {
+    fn new(columns: MasonryColumns) -> Masonry {
+        Masonry {
+            columns,
+            placed: Vec::new(),
+            measure: Vec::new(),
+        }
+    }
 }
 
 impl Row {
     pub fn new(size: Size, align: Align) -> Row {
         Row {
             size,
+            sticky: false,
             cells: Vec::new(),
             align,
+            flow: None,
+            masonry: None,
+            frame: None,
+            collapse: None,
+            visible: true,
         }
     }
 
     fn align(&mut self, align: Align) {
         self.align = align;
     }
+
+    // Whether this row has to be resolved before it can be laid out
+    fn needs_resolving(&self) -> bool {
+        self.flow.is_some()
+            || self.masonry.is_some()
+            || !self.visible
+            || self.cells.iter().any(|cell| !cell.visible)
+    }
+
+    // Whether this row or any of its cells can collapse
+    fn collapsible(&self) -> bool {
+        self.collapse.is_some() || self.cells.iter().any(|cell| cell.collapse.is_some())
+    }
+
+    fn is_collapsing(&self) -> bool {
+        Collapse::is_active(self.collapse)
+    }
+
+    // Animate the collapsing of this row & its cells, `index` being where the row was allocated
+    fn collapsing(&self, index: usize, collapsed: &Collapsed, id: Id, env: &Env) -> Row {
+        let mut row = self.clone();
+        if let Some(collapse) = row.collapse {
+            row.collapse = Some(collapse.animate(
+                id.with(("collapse", index)),
+                collapsed.rows.contains(&index),
+                env,
+            ));
+        }
+        if row.flow.is_none() && row.masonry.is_none() {
+            for (cell_index, cell) in row.cells.iter_mut().enumerate() {
+                if let Some(collapse) = cell.collapse {
+                    cell.collapse = Some(collapse.animate(
+                        id.with(("collapse", index, cell_index)),
+                        collapsed.cells.contains(&(index, cell_index)),
+                        env,
+                    ));
+                }
+            }
+        }
+        row
+    }
+
+    // Turn a flow into the regular rows it wraps onto, and place the cells of a masonry. Regular rows are returned as they are
+    fn resolve(&self, width: f32, spacing: Vec2, id: Id, env: &Env) -> Vec<Row> {
+        if let Some(masonry) = &self.masonry {
+            return vec![self.stack(masonry.columns, width, spacing, id, env)];
+        }
+        let flow = match &self.flow {
+            Some(flow) => flow,
+            None => return vec![self.clone()],
+        };
+        // The template cell was hidden
+        if self.cells.is_empty() {
+            return Vec::new();
+        }
+
+        // Fit as many cells as possible, then stretch them to fill the row
+        let per_row = fit(width, flow.width.min, spacing.x, flow.amount);
+        let cell_width = flow
+            .width
+            .clamp((width - spacing.x * (per_row - 1) as f32) / per_row as f32);
+
+        let mut template = self.cells[0].clone();
+        template.size = Size::exact(cell_width);
+
+        let mut rows = Vec::new();
+        let mut remaining = flow.amount;
+        while remaining > 0 {
+            let mut row = Row::new(self.size, self.align);
+            row.sticky = self.sticky;
+            row.collapse = self.collapse;
+            row.cells = vec![template.clone(); remaining.min(per_row)];
+            remaining -= row.cells.len();
+            rows.push(row);
+        }
+        rows
+    }
+
+    // Place each cell of a masonry into the shortest column, the row becoming as tall as the tallest one
+    fn stack(&self, columns: MasonryColumns, width: f32, spacing: Vec2, id: Id, env: &Env) -> Row {
+        let columns = match columns {
+            MasonryColumns::Fixed(columns) => columns,
+            MasonryColumns::Auto(min_width) => fit(width, min_width, spacing.x, self.cells.len()),
+        };
+        let column_width = ((width - spacing.x * (columns - 1) as f32) / columns as f32).max(0.);
+
+        let mut masonry = Masonry::new(MasonryColumns::Fixed(columns));
+        let mut heights = vec![0.; columns];
+        for (index, cell) in self.cells.iter().enumerate() {
+            let margin = cell.margin.sum().y;
+            let mut measure = None;
+            let height = match cell.size {
+                Size::Absolute { initial, .. } => initial,
+                Size::Relative { fraction, range } => range.clamp(column_width * fraction),
+                Size::Remainder { range } => {
+                    let key = id.with(("masonry", index));
+                    measure = Some(key);
+                    let measured = env
+                        .ctx
+                        .as_ref()
+                        .and_then(|ctx| ctx.data(|data| data.get_temp::<f32>(key)));
+                    range.clamp(measured.map_or(column_width, |height| height + margin))
+                }
+            };
+
+            // Find the shortest column, going with the left-most one when tied
+            let mut column = 0;
+            for (other, other_height) in heights.iter().enumerate() {
+                if *other_height < heights[column] {
+                    column = other;
+                }
+            }
+            masonry.placed.push(Rect::from_min_size(
+                Pos2::new(column as f32 * (column_width + spacing.x), heights[column]),
+                Vec2::new(column_width, height),
+            ));
+            masonry.measure.push(measure);
+            heights[column] += height + spacing.y;
+        }
+
+        let mut row = self.clone();
+        row.size = Size::exact(
+            heights
+                .iter()
+                .fold(0., |a: f32, b| a.max(*b - spacing.y))
+                .max(0.),
+        );
+        row.masonry = Some(masonry);
+        row
+    }
+
+    // Work out the rect of each cell in this row, given the top-left of the row
+    fn place(&self, origin: Pos2, width: f32, height: f32, spacing: Vec2) -> Vec<Rect> {
+        if let Some(masonry) = &self.masonry {
+            return masonry
+                .placed
+                .iter()
+                .map(|rect| rect.translate(origin.to_vec2()))
+                .collect();
+        }
+
+        // Get cell sizes
+        let cell_lengths = cell_set_as_f32(&self.cells, &spacing.x, &width);
+
+        // sum of the lengths + spacing
+        let mut length_sum = -spacing.x; // minus spacing to counter balance the extra spacing added at the end of the for loop
+        for length in cell_lengths.iter() {
+            length_sum += length + spacing.x;
+        }
+        // apply align offset
+        let grand_offset: f32 = {
+            match &self.align {
+                Align::Min => 0.,
+                Align::Center => (width - length_sum) * 0.5,
+                Align::Max => width - length_sum,
+            }
+        };
+
+        let mut pointer = origin.x + grand_offset;
+        let mut slots = Vec::new();
+        for length in cell_lengths {
+            slots.push(Rect::from_min_size(
+                Pos2::new(pointer, origin.y),
+                Vec2::new(length, height),
+            ));
+            pointer += length + spacing.x;
+        }
+        slots
+    }
 }
 
 // Internal struct for the grid builder to keep track of the layout details
@@ -421,6 +1725,11 @@ pub(crate) struct Cell {
     margin: Margin,
     layout: Layout,
     pub group: Option<GridBuilder>,
+    pub name: Option<String>,
+    pub sense: Sense,
+    pub frame: Option<CellFrame>,
+    pub collapse: Option<Collapse>,
+    pub visible: bool,
 }
 
 impl Cell {
@@ -430,6 +1739,11 @@ impl Cell {
             group: None,
             margin,
             layout,
+            name: None,
+            sense: Sense::hover(),
+            frame: None,
+            collapse: None,
+            visible: true,
         }
     }
 
@@ -456,11 +1770,52 @@ pub(crate) struct PureCell {
     rect: Rect,
     layout: Layout,
     clip: bool,
+    visible: Option<Rect>,
+    measure: Option<Id>,
+    name: Option<String>,
+    id: Id,
+    sense: Sense,
+    frame: Option<CellFrame>,
+    row: usize,
+    // Moved along with scrolling, by being sticky or frozen
+    pinned: bool,
+    // The rect before margins were applied
+    outer: Rect,
+    // How many grids deep this cell is nested
+    depth: usize,
+    // The sizes of the row & cell it came from
+    sizes: (Size, Size),
+    // The declared row & cell this came from, for each grid it's nested in
+    path: Vec<usize>,
 }
 
 impl PureCell {
     pub fn new(layout: Layout, clip: bool, rect: Rect) -> PureCell {
-        PureCell { layout, clip, rect }
+        PureCell {
+            layout,
+            clip,
+            rect,
+            visible: None,
+            measure: None,
+            name: None,
+            id: Id::NULL,
+            sense: Sense::hover(),
+            frame: None,
+            row: 0,
+            pinned: false,
+            outer: rect,
+            depth: 0,
+            sizes: (Size::remainder(), Size::remainder()),
+            path: Vec::new(),
+        }
+    }
+
+    // Shrink the area this cell is allowed to paint in
+    pub fn restrict(&mut self, area: Rect) {
+        self.visible = Some(match self.visible {
+            Some(visible) => visible.intersect(area),
+            None => area,
+        });
     }
 
     pub fn rect(&self) -> Rect {
@@ -472,4 +1827,450 @@ impl PureCell {
     pub fn clip(&self) -> bool {
         self.clip
     }
+    pub fn visible(&self) -> Option<Rect> {
+        self.visible
+    }
+    pub fn measure(&self) -> Option<Id> {
+        self.measure
+    }
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+    pub fn id(&self) -> Id {
+        self.id
+    }
+    pub fn sense(&self) -> Sense {
+        self.sense
+    }
+    pub fn frame(&self) -> Option<CellFrame> {
+        self.frame
+    }
+    pub fn row(&self) -> usize {
+        self.row
+    }
+    pub fn outer(&self) -> Rect {
+        self.outer
+    }
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+    pub fn sizes(&self) -> (Size, Size) {
+        self.sizes
+    }
+    pub fn path(&self) -> &[usize] {
+        &self.path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::{pos2, Style};
+
+    fn lay_out(grid: &GridBuilder, width: f32, height: f32) -> Computed {
+        let rect = Rect::from_min_max(pos2(0., 0.), pos2(width, height));
+        let env = Env {
+            ctx: None,
+            spacing: Style::default().spacing.item_spacing,
+        };
+        grid.to_real_cells(rect, rect, Id::new("egui_grid"), &env)
+    }
+
+    #[test]
+    fn separators_follow_sticky_rows_and_frozen_cells() {
+        let mut grid = GridBuilder::new()
+            .spacing(10., 10.)
+            .separators(Separators::new(Stroke::new(1., egui::Color32::WHITE)))
+            .frozen_cells(1);
+        for row in 0..6 {
+            grid = grid
+                .new_row(Size::exact(20.))
+                .sticky(row == 0)
+                .cells(Size::exact(50.), 3);
+        }
+        // Scrolled down past the first two rows & along past the first cell
+        let rect = Rect::from_min_max(pos2(0., 0.), pos2(400., 1000.));
+        let viewport = Rect::from_min_max(pos2(30., 65.), pos2(400., 1000.));
+        let env = Env {
+            ctx: None,
+            spacing: Style::default().spacing.item_spacing,
+        };
+        let computed = grid.to_real_cells(rect, viewport, Id::new("egui_grid"), &env);
+        let lines: Vec<Rect> = computed.lines.iter().map(|(line, _)| *line).collect();
+
+        // The line under the sticky row moves along with it, the ones scrolled beneath it are gone
+        let horizontal: Vec<f32> = lines
+            .iter()
+            .filter(|line| line.height() == 0.)
+            .map(|line| line.min.y)
+            .collect();
+        assert_eq!(horizontal, vec![90., 85., 115., 145.]);
+
+        // The sticky row keeps its lines, with the one after the frozen cell following it along
+        let vertical: Vec<Rect> = lines
+            .iter()
+            .filter(|line| line.width() == 0.)
+            .copied()
+            .collect();
+        assert!(vertical.contains(&Rect::from_min_max(pos2(85., 65.), pos2(85., 85.))));
+        assert!(vertical.contains(&Rect::from_min_max(pos2(115., 65.), pos2(115., 85.))));
+        // Rows scrolled beneath the sticky row lose their lines entirely
+        assert!(vertical.iter().all(|line| line.min.y >= 65.));
+        assert!(vertical
+            .iter()
+            .all(|line| line.min.y < 85. || line.min.y >= 90.));
+        assert!(vertical.contains(&Rect::from_min_max(pos2(85., 90.), pos2(85., 110.))));
+    }
+
+    #[test]
+    fn cells_keep_their_ids_wherever_they_end_up() {
+        let ids = |grid: &GridBuilder, width: f32| -> Vec<Id> {
+            lay_out(grid, width, 500.)
+                .cells
+                .iter()
+                .map(|cell| cell.id())
+                .collect()
+        };
+
+        // Flows wrapping onto more rows
+        let flow = GridBuilder::new()
+            .spacing(0., 0.)
+            .new_flow(Size::exact(20.), 50., 100., 4);
+        assert_eq!(ids(&flow, 200.), ids(&flow, 100.));
+
+        // Cells before them being hidden
+        let grid = |visible: bool| {
+            GridBuilder::new()
+                .new_row(Size::exact(20.))
+                .cell(Size::remainder())
+                .cell(Size::remainder())
+                .visible(visible)
+                .cell(Size::remainder())
+        };
+        let (shown, hidden) = (ids(&grid(true), 300.), ids(&grid(false), 300.));
+        assert_eq!(hidden, vec![shown[0], shown[2]]);
+    }
+
+    #[test]
+    fn named_cells_keep_their_id_when_nested() {
+        let id = |grid: &GridBuilder| {
+            lay_out(grid, 300., 300.)
+                .cells
+                .iter()
+                .find(|cell| cell.name() == Some("content"))
+                .map(|cell| cell.id())
+        };
+        let top = GridBuilder::new()
+            .new_row(Size::remainder())
+            .cell(Size::remainder())
+            .named("content");
+        let nested = GridBuilder::new()
+            .new_row(Size::exact(20.))
+            .new_row(Size::remainder())
+            .cell(Size::remainder())
+            .nest(
+                GridBuilder::new()
+                    .new_row(Size::remainder())
+                    .cell(Size::exact(40.))
+                    .cell(Size::remainder())
+                    .named("content"),
+            );
+        assert!(id(&top).is_some());
+        assert_eq!(id(&top), id(&nested));
+    }
+
+    #[test]
+    fn exports_skip_flows_without_a_cell() {
+        let mut grid = GridBuilder::new().new_flow(Size::exact(20.), 50., 100., 4);
+        grid.units[0].cells.clear();
+        assert!(!grid.to_html().contains("egui-grid-cell"));
+        assert!(lay_out(&grid, 300., 300.).cells.is_empty());
+    }
+
+    #[test]
+    fn flows_wrap_onto_as_many_rows_as_needed() {
+        let grid = GridBuilder::new()
+            .spacing(10., 5.)
+            .new_flow(Size::exact(20.), 90., 200., 7);
+        let computed = lay_out(&grid, 300., 500.);
+
+        // 3 cells fit in 300 points, stretched to fill the row
+        let rects: Vec<Rect> = computed.cells.iter().map(|cell| cell.rect()).collect();
+        assert_eq!(rects.len(), 7);
+        assert_eq!(computed.rows.len(), 3);
+        let width = (300. - 20.) / 3.;
+        for (index, rect) in rects.iter().enumerate() {
+            let (row, column) = (index / 3, index % 3);
+            assert!((rect.min.x - column as f32 * (width + 10.)).abs() < 0.01);
+            assert!((rect.width() - width).abs() < 0.01);
+            assert_eq!(rect.min.y, row as f32 * 25.);
+            assert_eq!(rect.height(), 20.);
+        }
+    }
+
+    #[test]
+    fn flows_keep_to_their_max_width() {
+        let grid = GridBuilder::new()
+            .spacing(0., 0.)
+            .new_flow(Size::exact(20.), 50., 80., 4);
+        let computed = lay_out(&grid, 1000., 500.);
+        assert_eq!(computed.rows.len(), 1);
+        assert!(computed.cells.iter().all(|cell| cell.rect().width() == 80.));
+    }
+
+    #[test]
+    fn automatic_orientation_holds_until_past_the_threshold() {
+        let env = Env {
+            ctx: Some(Context::default()),
+            spacing: Vec2::ZERO,
+        };
+        let id = Id::new("orientation");
+        let orientation = Orientation::Auto(0.1);
+        let columns = |width: f32, height: f32| {
+            let rect = Rect::from_min_size(pos2(0., 0.), Vec2::new(width, height));
+            orientation.is_columns(rect, id, &env)
+        };
+
+        // Starts off going by whichever way is longer
+        assert!(!columns(100., 90.));
+        // Near square, it stays as it was either way
+        assert!(!columns(100., 105.));
+        assert!(!columns(100., 110.));
+        assert!(!columns(100., 100.));
+        // Switching once 10% taller than wide
+        assert!(columns(100., 111.));
+        assert!(columns(105., 100.));
+        assert!(columns(110., 100.));
+        assert!(columns(100., 100.));
+        // & back once 10% wider than tall
+        assert!(!columns(111., 100.));
+        assert!(!columns(100., 105.));
+
+        // Without anywhere to remember it, it only goes by the rect
+        let env = Env {
+            ctx: None,
+            spacing: Vec2::ZERO,
+        };
+        let rect = Rect::from_min_size(pos2(0., 0.), Vec2::new(100., 105.));
+        assert!(orientation.is_columns(rect, id, &env));
+        assert!(!Orientation::Rows.is_columns(rect, id, &env));
+        assert!(Orientation::Columns.is_columns(rect, id, &env));
+    }
+
+    // The rect of the cell at the given path
+    fn rect_at(computed: &Computed, path: &[usize]) -> Rect {
+        computed
+            .cells
+            .iter()
+            .find(|cell| cell.path() == path)
+            .map(PureCell::rect)
+            .expect("a cell at the path")
+    }
+
+    #[test]
+    fn right_to_left_grids_mirror_alignment_and_layouts() {
+        let x_range = |grid: &GridBuilder| {
+            let computed = lay_out(grid, 200., 100.);
+            let rect = rect_at(&computed, &[0, 0]);
+            ((rect.min.x, rect.max.x), computed.cells[0].layout())
+        };
+        for (align, left, right) in [(Align::Min, 0., 150.), (Align::Max, 150., 0.)] {
+            let grid = GridBuilder::new()
+                .new_row_align(Size::remainder(), align)
+                .cell(Size::exact(50.));
+            let (range, layout) = x_range(&grid);
+            assert_eq!(range, (left, left + 50.));
+            let (range, rtl_layout) = x_range(&grid.clone().right_to_left(true));
+            assert_eq!(range, (right, right + 50.));
+            assert_eq!(rtl_layout, mirror_layout(layout));
+            // Only right-to-left grids flip the layout of their cells
+            let (range, mirrored_layout) = x_range(&grid.mirror_horizontally(true));
+            assert_eq!(range, (right, right + 50.));
+            assert_eq!(mirrored_layout, layout);
+        }
+    }
+
+    #[test]
+    fn nested_grids_mirrored_twice_keep_their_order() {
+        let nested = |mirror: bool| {
+            GridBuilder::new()
+                .spacing(0., 0.)
+                .new_row(Size::remainder())
+                .cells(Size::exact(50.), 2)
+                .mirror_horizontally(mirror)
+        };
+        let grid = |mirror: bool| {
+            GridBuilder::new()
+                .spacing(0., 0.)
+                .new_row(Size::remainder())
+                .cell(Size::exact(100.))
+                .nest(nested(mirror))
+                .cell(Size::exact(100.))
+                .mirror_horizontally(true)
+        };
+        let x = |computed: &Computed, path: &[usize]| {
+            let rect = rect_at(computed, path);
+            (rect.min.x, rect.max.x)
+        };
+
+        // Mirrored along with the grid holding it, the nested grid's cells swap places
+        let computed = lay_out(&grid(false), 200., 100.);
+        assert_eq!(x(&computed, &[0, 1]), (0., 100.));
+        assert_eq!(x(&computed, &[0, 0, 0, 0]), (150., 200.));
+        assert_eq!(x(&computed, &[0, 0, 0, 1]), (100., 150.));
+
+        // Mirrored again, they're back in order within the moved cell
+        let computed = lay_out(&grid(true), 200., 100.);
+        assert_eq!(x(&computed, &[0, 0, 0, 0]), (100., 150.));
+        assert_eq!(x(&computed, &[0, 0, 0, 1]), (150., 200.));
+    }
+
+    #[test]
+    fn unbounded_grids_mirror_around_their_rows() {
+        let grid = GridBuilder::new()
+            .spacing(0., 0.)
+            .new_row(Size::exact(20.))
+            .cell(Size::exact(50.))
+            .new_row(Size::exact(30.))
+            .cell(Size::exact(50.))
+            .mirror_vertically(true)
+            .right_to_left(true);
+        // As in a vertical ScrollArea
+        let computed = lay_out(&grid, 200., f32::INFINITY);
+        assert_eq!(
+            rect_at(&computed, &[0, 0]),
+            Rect::from_min_max(pos2(150., 30.), pos2(200., 50.))
+        );
+        assert_eq!(
+            rect_at(&computed, &[1, 0]),
+            Rect::from_min_max(pos2(150., 0.), pos2(200., 30.))
+        );
+    }
+
+    // Stack the first row of a grid holding a masonry, with 10 points of spacing
+    fn stack(grid: &GridBuilder, width: f32) -> (usize, Vec<Rect>, f32) {
+        let row = &grid.units[0];
+        let columns = row.masonry.as_ref().expect("a masonry").columns;
+        let env = Env {
+            ctx: None,
+            spacing: Vec2::splat(10.),
+        };
+        let stacked = row.stack(columns, width, env.spacing, Id::new("masonry"), &env);
+        let masonry = stacked.masonry.expect("a masonry");
+        let columns = match masonry.columns {
+            MasonryColumns::Fixed(columns) => columns,
+            MasonryColumns::Auto(_) => unreachable!("stacking settles the column count"),
+        };
+        let height = match stacked.size {
+            Size::Absolute { initial, .. } => initial,
+            _ => unreachable!("stacked masonries have an exact height"),
+        };
+        (columns, masonry.placed, height)
+    }
+
+    #[test]
+    fn masonry_cells_go_into_the_shortest_column() {
+        let grid = GridBuilder::new()
+            .new_masonry(3)
+            .cell(Size::exact(50.))
+            .cell(Size::exact(30.))
+            .cell(Size::exact(40.))
+            .cell(Size::exact(20.))
+            .cell(Size::exact(10.));
+        // Columns are 100 points wide, with 10 points between them
+        let (columns, placed, height) = stack(&grid, 320.);
+        assert_eq!(columns, 3);
+        let at =
+            |x: f32, y: f32, height: f32| Rect::from_min_size(pos2(x, y), Vec2::new(100., height));
+        assert_eq!(
+            placed,
+            vec![
+                // Ties go to the left-most column
+                at(0., 0., 50.),
+                at(110., 0., 30.),
+                at(220., 0., 40.),
+                // Then to whichever is shortest
+                at(110., 40., 20.),
+                at(220., 50., 10.),
+            ]
+        );
+        // As tall as the tallest column
+        assert_eq!(height, 60.);
+    }
+
+    #[test]
+    fn masonry_heights_are_relative_to_the_column_width() {
+        let grid = GridBuilder::new()
+            .new_masonry(2)
+            .cell(Size::relative(0.5))
+            .cell(Size::relative(1.).at_most(80.))
+            .cell(Size::remainder());
+        let (_, placed, height) = stack(&grid, 210.);
+        let heights: Vec<f32> = placed.iter().map(|rect| rect.height()).collect();
+        // Without anything measured, remainders are as tall as the column is wide
+        assert_eq!(heights, vec![50., 80., 100.]);
+        assert_eq!(height, 160.);
+    }
+
+    #[test]
+    fn automatic_masonries_fit_as_many_columns_as_they_can() {
+        let grid = GridBuilder::new()
+            .new_masonry_auto(100.)
+            .cells(Size::exact(10.), 5);
+        assert_eq!(stack(&grid, 320.).0, 3);
+        assert_eq!(stack(&grid, 200.).0, 1);
+        // Never more columns than cells
+        assert_eq!(stack(&grid, 1000.).0, 5);
+
+        let (columns, placed, _) = stack(&grid, 430.);
+        assert_eq!(columns, 4);
+        assert!(placed.iter().all(|rect| rect.width() == 100.));
+    }
+
+    #[test]
+    fn cells_report_the_sizes_they_were_declared_with() {
+        let described = |computed: &Computed, index: usize| {
+            let (row, cell) = computed.cells[index].sizes();
+            (format!("{row:?}"), format!("{cell:?}"))
+        };
+        let grid = GridBuilder::new()
+            .spacing(0., 0.)
+            .new_flow(Size::relative(0.3), 60., 100., 4)
+            .new_masonry(2)
+            .cell(Size::relative(0.5));
+        let computed = lay_out(&grid, 250., 500.);
+        // Flows are stretched to fill their rows, but still report the width they started from
+        assert_eq!(computed.cells[0].rect().width(), 62.5);
+        assert_eq!(
+            described(&computed, 3),
+            (
+                format!("{:?}", Size::relative(0.3)),
+                format!("{:?}", Size::exact(60.))
+            )
+        );
+        // Masonries are given an exact height once stacked, but report the size they were declared with
+        assert_eq!(
+            described(&computed, 4),
+            (
+                format!("{:?}", Size::remainder()),
+                format!("{:?}", Size::relative(0.5))
+            )
+        );
+    }
+
+    #[test]
+    fn flows_without_a_min_width_still_wrap() {
+        let grid = GridBuilder::new()
+            .spacing(0., 0.)
+            .new_flow(Size::exact(20.), 0., 0., 300);
+        let computed = lay_out(&grid, 100., 500.);
+        assert_eq!(computed.cells.len(), 300);
+        assert_eq!(computed.rows.len(), 3);
+
+        let masonry = GridBuilder::new()
+            .new_masonry_auto(0.)
+            .cells(Size::exact(10.), 3);
+        let computed = lay_out(&masonry, 100., 500.);
+        assert_eq!(computed.cells.len(), 3);
+    }
 }
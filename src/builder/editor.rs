@@ -0,0 +1,897 @@
+// Lets users rearrange a layout by hand, working on the rows & cells of a GridBuilder along with any grids nested in them
+
+use super::*;
+use egui::{Align2, Button, CursorIcon, FontId, Response};
+use std::collections::HashMap;
+
+// How close borders can be dragged to one another
+const MIN_LENGTH: f32 = 8.;
+// How wide the area for dragging a border is
+const BORDER_WIDTH: f32 = 6.;
+
+/// Lets users rearrange a layout for themselves, like the tiles of a customizable workspace.
+///
+/// Each cell of the layout is drawn as a tile. Right-clicking a tile lets it be named, split in two side by side or stacked,
+/// merged with the cell after it, or deleted (giving its space to a neighbor). The borders between tiles can be dragged to resize them.
+///
+/// Splitting a cell adds another to its row when it can, and otherwise nests a grid within it (see [`GridBuilder::nest`]).
+/// Nested grids left holding a single cell are folded back into the cell holding them.
+///
+/// Fill the tiles by name, as cells come & go while editing. With the `serde` feature enabled, the [`Self::layout`] can be saved & loaded to keep a user's layout around.
+///
+/// ## Example
+/// ```
+/// use egui_grid::{GridBuilder, LayoutEditor};
+/// use egui_extras::Size;
+///
+/// # fn demo(ui: &mut egui::Ui, editing: bool) {
+/// let mut editor = LayoutEditor::new(
+///     GridBuilder::new()
+///         .new_row(Size::remainder())
+///         .cell(Size::relative(0.25)).named("files")
+///         .cell(Size::remainder()).named("editor"),
+/// );
+///
+/// if editing {
+///     if editor.show(ui).changed() {
+///         // Save editor.layout() somewhere
+///     }
+/// } else {
+///     editor.layout().clone().show(ui, |mut grid| {
+///         grid.named("files", |ui| {
+///             ui.label("Files");
+///         });
+///         grid.named("editor", |ui| {
+///             ui.label("Editor");
+///         });
+///     });
+/// }
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct LayoutEditor {
+    layout: GridBuilder,
+    id_source: Option<Id>,
+}
+
+impl Default for LayoutEditor {
+    fn default() -> Self {
+        LayoutEditor::new(
+            GridBuilder::new()
+                .new_row(Size::remainder())
+                .cell(Size::remainder()),
+        )
+    }
+}
+
+// Where everything ended up, to work out what edits do
+#[derive(Default)]
+struct Placed {
+    // The outer rect of each cell, and the space taken up by each row, by path
+    areas: HashMap<Vec<usize>, Rect>,
+    // The rect of each grid & whether its rows are columns, by path
+    grids: HashMap<Vec<usize>, (Rect, bool)>,
+    // The path & rect of each cell without a grid nested in it
+    tiles: Vec<(Vec<usize>, Rect)>,
+    borders: Vec<Border>,
+}
+
+// What lies between two neighboring rows, or cells of the same row
+struct Border {
+    // The path of the first row or cell, the second being the one after it
+    first: Vec<usize>,
+    rect: Rect,
+    // Whether the two sit side by side, rather than one over the other
+    side_by_side: bool,
+}
+
+enum Edit {
+    Name(Vec<usize>, Option<String>),
+    Split(Vec<usize>, bool),
+    Merge(Vec<usize>),
+    Delete(Vec<usize>),
+    Resize(Vec<usize>, f32),
+}
+
+impl LayoutEditor {
+    /// Start editing a layout.
+    pub fn new(layout: GridBuilder) -> LayoutEditor {
+        LayoutEditor {
+            layout,
+            id_source: None,
+        }
+    }
+
+    /// A source for the editor's unique [`Id`](https://docs.rs/egui/latest/egui/struct.Id.html).
+    /// Without one, the editor's id comes from its position in the ui, so it's only needed when editors may come & go or change order.
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// The layout as it's been edited.
+    pub fn layout(&self) -> &GridBuilder {
+        &self.layout
+    }
+
+    /// Replace the layout being edited, such as with one that was saved.
+    pub fn set_layout(&mut self, layout: GridBuilder) {
+        self.layout = layout;
+    }
+
+    /// Show the layout as tiles filling the space available, to be edited by the user.
+    ///
+    /// The response is marked as changed whenever the layout is edited.
+    pub fn show(&mut self, ui: &mut Ui) -> Response {
+        let rect = ui.available_rect_before_wrap();
+        let id = match self.id_source {
+            Some(id_source) => ui.make_persistent_id(id_source),
+            None => {
+                let id = ui.next_auto_id();
+                ui.skip_ahead_auto_ids(1);
+                id
+            }
+        };
+        let env = Env {
+            ctx: None,
+            spacing: ui.style().spacing.item_spacing,
+        };
+        let mut placed = Placed::default();
+        self.layout
+            .place_tiles(rect, id, &env, &mut Vec::new(), &mut placed);
+
+        let mut edit = None;
+        let only_tile = placed.tiles.len() == 1;
+        for (path, tile) in placed.tiles.iter() {
+            let response = ui.interact(*tile, id.with(("tile", path)), Sense::click());
+            let visuals = if response.hovered() {
+                ui.visuals().widgets.hovered
+            } else {
+                ui.visuals().widgets.inactive
+            };
+            ui.painter().rect(
+                *tile,
+                visuals.rounding,
+                ui.visuals().extreme_bg_color,
+                visuals.bg_stroke,
+            );
+            let name = self.layout.cell_at(path).and_then(|cell| cell.name.clone());
+            ui.painter().text(
+                tile.center(),
+                Align2::CENTER_CENTER,
+                name.as_deref().unwrap_or("Unnamed"),
+                FontId::proportional(14.),
+                visuals.text_color(),
+            );
+
+            response.context_menu(|ui| {
+                let mut text = name.clone().unwrap_or_default();
+                ui.horizontal(|ui| {
+                    ui.label("Name");
+                    if ui.text_edit_singleline(&mut text).changed() {
+                        let name = (!text.is_empty()).then_some(text);
+                        edit = Some(Edit::Name(path.clone(), name));
+                    }
+                });
+                ui.separator();
+                if ui.button("Split side by side").clicked() {
+                    edit = Some(Edit::Split(path.clone(), false));
+                    ui.close_menu();
+                }
+                if ui.button("Split stacked").clicked() {
+                    edit = Some(Edit::Split(path.clone(), true));
+                    ui.close_menu();
+                }
+                if ui
+                    .add_enabled(self.layout.can_merge(path), Button::new("Merge with next"))
+                    .clicked()
+                {
+                    edit = Some(Edit::Merge(path.clone()));
+                    ui.close_menu();
+                }
+                if ui.add_enabled(!only_tile, Button::new("Delete")).clicked() {
+                    edit = Some(Edit::Delete(path.clone()));
+                    ui.close_menu();
+                }
+            });
+        }
+
+        // Borders go over the tiles, so they can be grabbed
+        for border in placed.borders.iter() {
+            let response = ui.interact(
+                border.rect,
+                id.with(("border", &border.first)),
+                Sense::drag(),
+            );
+            if response.hovered() || response.dragged() {
+                ui.ctx().set_cursor_icon(if border.side_by_side {
+                    CursorIcon::ResizeHorizontal
+                } else {
+                    CursorIcon::ResizeVertical
+                });
+                ui.painter().rect_filled(
+                    border.rect,
+                    0.,
+                    ui.visuals().widgets.active.bg_stroke.color,
+                );
+            }
+            if response.dragged() {
+                let delta = if border.side_by_side {
+                    response.drag_delta().x
+                } else {
+                    response.drag_delta().y
+                };
+                if delta != 0. {
+                    edit = Some(Edit::Resize(border.first.clone(), delta));
+                }
+            }
+        }
+
+        let mut response = ui.allocate_rect(rect, Sense::hover());
+        if let Some(edit) = edit {
+            if self.layout.edit(edit, &placed) {
+                response.mark_changed();
+            }
+        }
+        response
+    }
+}
+
+impl GridBuilder {
+    // Lay out each grid on its own, so the rect of every cell & row is known, nested grids included
+    fn place_tiles(
+        &self,
+        rect: Rect,
+        id: Id,
+        env: &Env,
+        path: &mut Vec<usize>,
+        placed: &mut Placed,
+    ) {
+        let mut shallow = self.clone();
+        shallow.breakpoints.clear();
+        for cell in shallow
+            .units
+            .iter_mut()
+            .flat_map(|row| row.cells.iter_mut())
+        {
+            cell.group = None;
+        }
+        let columns = shallow.orientation.is_columns(rect, id, env);
+        placed.grids.insert(path.clone(), (rect, columns));
+        let computed = shallow.to_real_cells(rect, rect, id, env);
+
+        let mut previous: Option<&PureCell> = None;
+        let mut rows: Vec<(usize, Rect)> = Vec::new();
+        for cell in computed.cells.iter() {
+            let (row, index) = (cell.path()[0], cell.path()[1]);
+            let full: Vec<usize> = path.iter().copied().chain([row, index]).collect();
+            placed.areas.insert(full.clone(), cell.outer());
+            match rows.last_mut() {
+                Some((last, area)) if *last == row => *area = area.union(cell.outer()),
+                _ => rows.push((row, cell.outer())),
+            }
+
+            // Borders between the cells of a row
+            if let Some(previous) = previous.filter(|previous| previous.path()[0] == row) {
+                if let Some(border) = border(previous.outer(), cell.outer()) {
+                    placed.borders.push(Border {
+                        first: path
+                            .iter()
+                            .copied()
+                            .chain([row, previous.path()[1]])
+                            .collect(),
+                        ..border
+                    });
+                }
+            }
+            previous = Some(cell);
+
+            match &self.units[row].cells[index].group {
+                Some(grid) => {
+                    path.extend([row, index]);
+                    grid.place_tiles(cell.rect(), id.with((row, index)), env, path, placed);
+                    path.truncate(path.len() - 2);
+                }
+                None => placed.tiles.push((full, cell.rect())),
+            }
+        }
+
+        // Borders between rows
+        for pair in rows.windows(2) {
+            let ((first, a), (_, b)) = (pair[0], pair[1]);
+            let full: Vec<usize> = path.iter().copied().chain([first]).collect();
+            placed.areas.insert(full.clone(), a);
+            if let Some(border) = border(a, b) {
+                placed.borders.push(Border {
+                    first: full,
+                    ..border
+                });
+            }
+        }
+        if let Some((last, area)) = rows.last() {
+            placed
+                .areas
+                .insert(path.iter().copied().chain([*last]).collect(), *area);
+        }
+    }
+
+    fn grid_at(&self, path: &[usize]) -> Option<&GridBuilder> {
+        match path {
+            [] => Some(self),
+            [row, cell, rest @ ..] => self
+                .units
+                .get(*row)?
+                .cells
+                .get(*cell)?
+                .group
+                .as_ref()?
+                .grid_at(rest),
+            _ => None,
+        }
+    }
+
+    fn grid_at_mut(&mut self, path: &[usize]) -> Option<&mut GridBuilder> {
+        match path {
+            [] => Some(self),
+            [row, cell, rest @ ..] => self
+                .units
+                .get_mut(*row)?
+                .cells
+                .get_mut(*cell)?
+                .group
+                .as_mut()?
+                .grid_at_mut(rest),
+            _ => None,
+        }
+    }
+
+    fn cell_at(&self, path: &[usize]) -> Option<&Cell> {
+        let [grid @ .., row, cell] = path else {
+            return None;
+        };
+        self.grid_at(grid)?.units.get(*row)?.cells.get(*cell)
+    }
+
+    // Whether there's a cell after this one in its row, or a row after this one if it has a row to itself
+    fn can_merge(&self, path: &[usize]) -> bool {
+        let [grid @ .., row, cell] = path else {
+            return false;
+        };
+        let Some(layout) = self.grid_at(grid) else {
+            return false;
+        };
+        let cells = layout.units.get(*row).map_or(0, |row| row.cells.len());
+        cell + 1 < cells || (cells == 1 && row + 1 < layout.units.len())
+    }
+
+    // Carry out an edit, returning whether anything changed
+    fn edit(&mut self, edit: Edit, placed: &Placed) -> bool {
+        let path = match &edit {
+            Edit::Name(path, _)
+            | Edit::Split(path, _)
+            | Edit::Merge(path)
+            | Edit::Delete(path)
+            | Edit::Resize(path, _) => path.clone(),
+        };
+        // Rows have paths of odd length, cells of even length
+        let (grid_path, index) = if path.len() % 2 == 1 {
+            (&path[..path.len() - 1], None)
+        } else {
+            (&path[..path.len() - 2], Some(path[path.len() - 1]))
+        };
+        let row = path[grid_path.len()];
+        let Some(&(grid_rect, columns)) = placed.grids.get(grid_path) else {
+            return false;
+        };
+        let Some(grid) = self.grid_at_mut(grid_path) else {
+            return false;
+        };
+        if row >= grid.units.len() || index.is_some_and(|cell| cell >= grid.units[row].cells.len())
+        {
+            return false;
+        }
+        // How long a rect is in the direction cells (or rows) are laid out in
+        let length = |rect: Rect, cells: bool| {
+            if cells != columns {
+                rect.width()
+            } else {
+                rect.height()
+            }
+        };
+        let area = |row: usize, cell: Option<usize>| {
+            let path: Vec<usize> = grid_path.iter().copied().chain([row]).chain(cell).collect();
+            placed.areas.get(&path).copied()
+        };
+        // The length of two neighbors, along with the gap between them
+        let span = |first: Rect, second: Rect, cells: bool| length(first.union(second), cells);
+
+        match (edit, index) {
+            (Edit::Name(_, name), Some(cell)) => {
+                grid.units[row].cells[cell].name = name;
+            }
+            (Edit::Split(_, stacked), Some(cell)) => {
+                let row_ref = &mut grid.units[row];
+                if stacked == columns {
+                    // Another cell along the row
+                    let split = &mut row_ref.cells[cell];
+                    split.size = halve(split.size);
+                    let mut added = split.clone();
+                    added.name = None;
+                    row_ref.cells.insert(cell + 1, added);
+                } else if row_ref.cells.len() == 1 {
+                    // Another row, as the cell has its row to itself
+                    row_ref.size = halve(row_ref.size);
+                    let mut added = row_ref.clone();
+                    for cell in added.cells.iter_mut() {
+                        cell.name = None;
+                    }
+                    grid.units.insert(row + 1, added);
+                } else {
+                    let mut nested = GridBuilder::new();
+                    nested.spacing = grid.spacing;
+                    nested.use_default_spacing = grid.use_default_spacing;
+                    nested = if stacked {
+                        nested
+                            .new_row(Size::remainder())
+                            .cell(Size::remainder())
+                            .new_row(Size::remainder())
+                            .cell(Size::remainder())
+                    } else {
+                        nested
+                            .new_row(Size::remainder())
+                            .cell(Size::remainder())
+                            .cell(Size::remainder())
+                    };
+                    let split = &mut grid.units[row].cells[cell];
+                    nested.units[0].cells[0].name = split.name.take();
+                    split.group = Some(nested);
+                }
+            }
+            (Edit::Merge(_), Some(cell)) => {
+                if cell + 1 < grid.units[row].cells.len() {
+                    let (Some(first), Some(second)) =
+                        (area(row, Some(cell)), area(row, Some(cell + 1)))
+                    else {
+                        return false;
+                    };
+                    let cells = &mut grid.units[row].cells;
+                    let next = cells.remove(cell + 1);
+                    let whole = length(grid_rect, true);
+                    cells[cell].size = combine(
+                        cells[cell].size,
+                        next.size,
+                        span(first, second, true),
+                        whole,
+                    );
+                    if cells[cell].name.is_none() {
+                        cells[cell].name = next.name;
+                    }
+                } else if grid.units[row].cells.len() == 1 && row + 1 < grid.units.len() {
+                    let (Some(first), Some(second)) = (area(row, None), area(row + 1, None)) else {
+                        return false;
+                    };
+                    let next = grid.units.remove(row + 1);
+                    let whole = length(grid_rect, false);
+                    grid.units[row].size = combine(
+                        grid.units[row].size,
+                        next.size,
+                        span(first, second, false),
+                        whole,
+                    );
+                } else {
+                    return false;
+                }
+            }
+            (Edit::Delete(_), Some(cell)) => {
+                if grid.units[row].cells.len() > 1 {
+                    // Whichever cell is beside it takes its space
+                    let neighbor = if cell > 0 { cell - 1 } else { cell + 1 };
+                    let (Some(first), Some(second)) =
+                        (area(row, Some(cell)), area(row, Some(neighbor)))
+                    else {
+                        return false;
+                    };
+                    let cells = &mut grid.units[row].cells;
+                    let whole = length(grid_rect, true);
+                    cells[neighbor].size = combine(
+                        cells[neighbor].size,
+                        cells[cell].size,
+                        span(first, second, true),
+                        whole,
+                    );
+                    cells.remove(cell);
+                } else if grid.units.len() > 1 {
+                    let neighbor = if row > 0 { row - 1 } else { row + 1 };
+                    let (Some(first), Some(second)) = (area(row, None), area(neighbor, None))
+                    else {
+                        return false;
+                    };
+                    let whole = length(grid_rect, false);
+                    grid.units[neighbor].size = combine(
+                        grid.units[neighbor].size,
+                        grid.units[row].size,
+                        span(first, second, false),
+                        whole,
+                    );
+                    grid.units.remove(row);
+                } else if let Some(holder) = grid_path.len().checked_sub(2) {
+                    // The last cell of a nested grid takes the grid with it
+                    if let Some(parent) = self.grid_at_mut(&grid_path[..holder]) {
+                        parent.units[grid_path[holder]].cells[grid_path[holder + 1]].group = None;
+                    }
+                    return true;
+                } else {
+                    return false;
+                }
+            }
+            (Edit::Resize(_, delta), cell) => {
+                let (first, second, whole) = match cell {
+                    Some(cell) => (
+                        area(row, Some(cell)),
+                        area(row, Some(cell + 1)),
+                        length(grid_rect, true),
+                    ),
+                    None => (
+                        area(row, None),
+                        area(row + 1, None),
+                        length(grid_rect, false),
+                    ),
+                };
+                let (Some(first), Some(second)) = (first, second) else {
+                    return false;
+                };
+                let (a, b) = (
+                    length(first, cell.is_some()),
+                    length(second, cell.is_some()),
+                );
+                if a + b < MIN_LENGTH * 2. {
+                    return false;
+                }
+                // Borders move along with the pointer, even when the grid is mirrored
+                let forward = if (cell.is_some()) != columns {
+                    first.min.x <= second.min.x
+                } else {
+                    first.min.y <= second.min.y
+                };
+                let delta = if forward { delta } else { -delta };
+                let a_length = (a + delta).clamp(MIN_LENGTH, a + b - MIN_LENGTH);
+                let b_length = a + b - a_length;
+                let (first_size, second_size) = match cell {
+                    Some(cell) => {
+                        let cells = &grid.units[row].cells;
+                        (cells[cell].size, cells[cell + 1].size)
+                    }
+                    None => (grid.units[row].size, grid.units[row + 1].size),
+                };
+                let (first_size, second_size) =
+                    resize_pair(first_size, second_size, a_length, b_length, whole);
+                match cell {
+                    Some(cell) => {
+                        let cells = &mut grid.units[row].cells;
+                        cells[cell].size = first_size;
+                        cells[cell + 1].size = second_size;
+                    }
+                    None => {
+                        grid.units[row].size = first_size;
+                        grid.units[row + 1].size = second_size;
+                    }
+                }
+            }
+            _ => return false,
+        }
+        self.fold(grid_path);
+        true
+    }
+
+    // Fold a nested grid left holding a single cell back into the cell holding it
+    fn fold(&mut self, path: &[usize]) {
+        let Some(holder) = path.len().checked_sub(2) else {
+            return;
+        };
+        let Some(parent) = self.grid_at_mut(&path[..holder]) else {
+            return;
+        };
+        let cell = &mut parent.units[path[holder]].cells[path[holder + 1]];
+        let single = cell
+            .group
+            .as_ref()
+            .and_then(|grid| match grid.units.as_slice() {
+                [row] if row.cells.len() == 1 && row.cells[0].group.is_none() => {
+                    Some(row.cells[0].name.clone())
+                }
+                _ => None,
+            });
+        if let Some(name) = single {
+            cell.group = None;
+            if cell.name.is_none() {
+                cell.name = name;
+            }
+        }
+    }
+}
+
+// Where to grab the border between two rects, if they're beside each other
+fn border(a: Rect, b: Rect) -> Option<Border> {
+    let x = a.x_range().intersection(b.x_range());
+    let y = a.y_range().intersection(b.y_range());
+    let (left, right) = if a.min.x <= b.min.x { (a, b) } else { (b, a) };
+    let (top, bottom) = if a.min.y <= b.min.y { (a, b) } else { (b, a) };
+    let (rect, side_by_side) = if y.span() > 0. && right.min.x >= left.max.x {
+        let middle = (left.max.x + right.min.x) / 2.;
+        (
+            Rect::from_x_y_ranges(Rangef::point(middle).expand(BORDER_WIDTH / 2.), y),
+            true,
+        )
+    } else if x.span() > 0. && bottom.min.y >= top.max.y {
+        let middle = (top.max.y + bottom.min.y) / 2.;
+        (
+            Rect::from_x_y_ranges(x, Rangef::point(middle).expand(BORDER_WIDTH / 2.)),
+            false,
+        )
+    } else {
+        return None;
+    };
+    Some(Border {
+        first: Vec::new(),
+        rect,
+        side_by_side,
+    })
+}
+
+fn halve(size: Size) -> Size {
+    match size {
+        Size::Absolute { initial, .. } => resize(size, initial / 2., 0.),
+        Size::Relative { fraction, range } => Size::Relative {
+            fraction: fraction / 2.,
+            range,
+        },
+        Size::Remainder { .. } => size,
+    }
+}
+
+// The size of two rows or cells made into one, undoing a split where possible
+fn combine(size: Size, other: Size, length: f32, whole: f32) -> Size {
+    match (size, other) {
+        (Size::Remainder { .. }, _) => size,
+        (_, Size::Remainder { .. }) => Size::remainder(),
+        (
+            Size::Relative { fraction, range },
+            Size::Relative {
+                fraction: other, ..
+            },
+        ) => Size::Relative {
+            fraction: (fraction + other).min(1.),
+            range,
+        },
+        (Size::Absolute { initial, .. }, Size::Absolute { initial: other, .. }) => {
+            resize(size, initial + other, whole)
+        }
+        _ => resize(size, length, whole),
+    }
+}
+
+// Resize two neighbors, leaving remainders to fill in whatever's left where possible
+fn resize_pair(first: Size, second: Size, a: f32, b: f32, whole: f32) -> (Size, Size) {
+    match (first, second) {
+        (_, Size::Remainder { .. }) => (resize(first, a, whole), second),
+        (Size::Remainder { .. }, _) => (first, resize(second, b, whole)),
+        _ => (resize(first, a, whole), resize(second, b, whole)),
+    }
+}
+
+// Keep the kind of size, other than remainders which become relative
+fn resize(size: Size, length: f32, whole: f32) -> Size {
+    let fraction = |length: f32| (length / whole.max(1.)).clamp(0., 1.);
+    match size {
+        Size::Absolute { range, .. } if range.min == range.max => Size::exact(length),
+        Size::Absolute { range, .. } => Size::Absolute {
+            initial: length,
+            range,
+        },
+        Size::Relative { range, .. } => Size::Relative {
+            fraction: fraction(length),
+            range,
+        },
+        Size::Remainder { range } => Size::Relative {
+            fraction: fraction(length),
+            range,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::pos2;
+
+    fn place(grid: &GridBuilder, width: f32, height: f32) -> Placed {
+        let rect = Rect::from_min_max(pos2(0., 0.), pos2(width, height));
+        let env = Env {
+            ctx: None,
+            spacing: Vec2::ZERO,
+        };
+        let mut placed = Placed::default();
+        grid.place_tiles(rect, Id::new("editor"), &env, &mut Vec::new(), &mut placed);
+        placed
+    }
+
+    // Carry out an edit as if the grid was shown at the given size
+    fn apply(grid: &mut GridBuilder, edit: Edit, width: f32, height: f32) -> bool {
+        let placed = place(grid, width, height);
+        grid.edit(edit, &placed)
+    }
+
+    // Sizes don't compare, but their debug output does
+    fn same(size: Size, other: Size) -> bool {
+        format!("{size:?}") == format!("{other:?}")
+    }
+
+    #[test]
+    fn sizes_are_halved_combined_and_resized_by_kind() {
+        assert!(same(halve(Size::exact(100.)), Size::exact(50.)));
+        assert!(same(halve(Size::relative(0.5)), Size::relative(0.25)));
+        assert!(same(halve(Size::remainder()), Size::remainder()));
+
+        // Combining undoes halving
+        assert!(same(
+            combine(Size::exact(50.), Size::exact(50.), 100., 400.),
+            Size::exact(100.)
+        ));
+        assert!(same(
+            combine(Size::relative(0.25), Size::relative(0.25), 100., 400.),
+            Size::relative(0.5)
+        ));
+        assert!(same(
+            combine(Size::exact(50.), Size::remainder(), 100., 400.),
+            Size::remainder()
+        ));
+        // Mixed kinds take on whatever length the two spanned
+        assert!(same(
+            combine(Size::relative(0.25), Size::exact(50.), 200., 400.),
+            Size::relative(0.5)
+        ));
+
+        // Remainders are left to fill in what's left
+        let (first, second) = resize_pair(Size::exact(50.), Size::remainder(), 80., 20., 100.);
+        assert!(same(first, Size::exact(80.)) && same(second, Size::remainder()));
+        let (first, second) = resize_pair(Size::remainder(), Size::relative(0.5), 80., 20., 100.);
+        assert!(same(first, Size::remainder()) && same(second, Size::relative(0.2)));
+    }
+
+    #[test]
+    fn splitting_a_cell_adds_a_cell_or_a_row() {
+        let mut grid = GridBuilder::new()
+            .new_row(Size::remainder())
+            .cell(Size::exact(200.))
+            .named("main");
+
+        // Side by side, along the row
+        assert!(apply(&mut grid, Edit::Split(vec![0, 0], false), 400., 400.));
+        assert_eq!(grid.units[0].cells.len(), 2);
+        assert!(grid.units[0]
+            .cells
+            .iter()
+            .all(|cell| same(cell.size, Size::exact(100.))));
+        assert_eq!(grid.units[0].cells[0].name.as_deref(), Some("main"));
+        assert_eq!(grid.units[0].cells[1].name, None);
+
+        // Stacked, with the cell having its row to itself
+        let mut grid = GridBuilder::new()
+            .new_row(Size::relative(0.5))
+            .cell(Size::remainder())
+            .named("main");
+        assert!(apply(&mut grid, Edit::Split(vec![0, 0], true), 400., 400.));
+        assert_eq!(grid.units.len(), 2);
+        assert!(grid
+            .units
+            .iter()
+            .all(|row| same(row.size, Size::relative(0.25))));
+        assert_eq!(grid.units[0].cells[0].name.as_deref(), Some("main"));
+        assert_eq!(grid.units[1].cells[0].name, None);
+    }
+
+    #[test]
+    fn splitting_across_a_shared_row_nests_a_grid() {
+        let mut grid = GridBuilder::new()
+            .new_row(Size::remainder())
+            .cell(Size::remainder())
+            .named("main")
+            .cell(Size::remainder());
+        assert!(apply(&mut grid, Edit::Split(vec![0, 0], true), 400., 400.));
+        assert_eq!(grid.units[0].cells.len(), 2);
+        let cell = &grid.units[0].cells[0];
+        assert_eq!(cell.name, None);
+        let nested = cell.group.as_ref().expect("a nested grid");
+        assert_eq!(nested.units.len(), 2);
+        assert_eq!(nested.units[0].cells[0].name.as_deref(), Some("main"));
+
+        // Its cells can be edited in turn, by their full path
+        assert!(grid.cell_at(&[0, 0, 1, 0]).is_some());
+        assert!(apply(&mut grid, Edit::Merge(vec![0, 0, 0, 0]), 400., 400.));
+        // Leaving a single cell folds it back into the cell holding it
+        let cell = &grid.units[0].cells[0];
+        assert!(cell.group.is_none());
+        assert_eq!(cell.name.as_deref(), Some("main"));
+    }
+
+    #[test]
+    fn merging_undoes_splitting() {
+        let mut grid = GridBuilder::new()
+            .new_row(Size::remainder())
+            .cell(Size::exact(100.))
+            .cell(Size::exact(100.))
+            .named("second");
+        assert!(grid.can_merge(&[0, 0]));
+        assert!(!grid.can_merge(&[0, 1]));
+        assert!(apply(&mut grid, Edit::Merge(vec![0, 0]), 400., 400.));
+        assert_eq!(grid.units[0].cells.len(), 1);
+        assert!(same(grid.units[0].cells[0].size, Size::exact(200.)));
+        // The name carries over when the first has none
+        assert_eq!(grid.units[0].cells[0].name.as_deref(), Some("second"));
+
+        // Rows merge when the cell has its row to itself
+        let mut grid = GridBuilder::new()
+            .new_row(Size::relative(0.25))
+            .cell(Size::remainder())
+            .new_row(Size::relative(0.25))
+            .cell(Size::remainder());
+        assert!(grid.can_merge(&[0, 0]));
+        assert!(apply(&mut grid, Edit::Merge(vec![0, 0]), 400., 400.));
+        assert_eq!(grid.units.len(), 1);
+        assert!(same(grid.units[0].size, Size::relative(0.5)));
+
+        // Nothing follows the last one
+        assert!(!grid.can_merge(&[0, 0]));
+        assert!(!apply(&mut grid, Edit::Merge(vec![0, 0]), 400., 400.));
+    }
+
+    #[test]
+    fn deleting_the_last_cell_of_a_nested_grid_removes_the_grid() {
+        let inner = GridBuilder::new()
+            .new_row(Size::remainder())
+            .cell(Size::remainder());
+        let mut grid = GridBuilder::new()
+            .new_row(Size::remainder())
+            .cell(Size::remainder())
+            .nest(
+                GridBuilder::new()
+                    .new_row(Size::remainder())
+                    .cell(Size::remainder())
+                    .nest(inner),
+            )
+            .cell(Size::remainder());
+        assert!(apply(&mut grid, Edit::Delete(vec![0, 0, 0, 0]), 400., 400.));
+        assert!(grid.units[0].cells[0].group.is_none());
+        assert_eq!(grid.units[0].cells.len(), 2);
+
+        // The last cell of the outermost grid stays
+        let mut grid = GridBuilder::new()
+            .new_row(Size::remainder())
+            .cell(Size::remainder());
+        assert!(!apply(&mut grid, Edit::Delete(vec![0, 0]), 400., 400.));
+        assert_eq!(grid.units[0].cells.len(), 1);
+    }
+
+    #[test]
+    fn borders_follow_the_pointer_in_mirrored_grids() {
+        let grid = GridBuilder::new()
+            .spacing(0., 0.)
+            .new_row(Size::remainder())
+            .cell(Size::exact(100.))
+            .cell(Size::exact(100.));
+
+        // Dragging right grows the first cell
+        let mut plain = grid.clone();
+        assert!(apply(&mut plain, Edit::Resize(vec![0, 0], 10.), 200., 100.));
+        assert!(same(plain.units[0].cells[0].size, Size::exact(110.)));
+        assert!(same(plain.units[0].cells[1].size, Size::exact(90.)));
+
+        // Mirrored, the first cell is on the right so dragging right shrinks it
+        let mut mirrored = grid.mirror_horizontally(true);
+        let placed = place(&mirrored, 200., 100.);
+        assert!(placed.areas[&vec![0, 0]].min.x > placed.areas[&vec![0, 1]].min.x);
+        assert!(mirrored.edit(Edit::Resize(vec![0, 0], 10.), &placed));
+        assert!(same(mirrored.units[0].cells[0].size, Size::exact(90.)));
+        assert!(same(mirrored.units[0].cells[1].size, Size::exact(110.)));
+    }
+}
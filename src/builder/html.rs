@@ -0,0 +1,263 @@
+// Turns a GridBuilder into nested flexboxes, following the same rules as to_real_cells where css allows it
+
+use super::*;
+use egui::{Direction, Style};
+use std::fmt::Write;
+
+impl GridBuilder {
+    /// Write this layout as HTML, using CSS flexbox to match how the grid is laid out, for sharing a layout with a web page.
+    ///
+    /// The grid becomes a `div` with the class `egui-grid`, with a `div` for each row (`egui-grid-row`) & cell (`egui-grid-cell`), styled inline.
+    /// Named cells are given a `data-name` attribute to find them by, and nested grids are placed within the cell holding them.
+    ///
+    /// Sizes along a row (or down the grid) are mapped as:
+    /// - `Size::exact` & `Size::initial` become a fixed size in `px`.
+    /// - `Size::relative` becomes a `%` of the grid, bounded by its range.
+    /// - `Size::remainder` grows to share what's left, like `1fr`, bounded by its range.
+    ///
+    /// Margins become padding, and aligns & [`Layout`](https://docs.rs/egui/latest/egui/struct.Layout.html)s become the flexbox equivalent.
+    /// Flows wrap, masonries become CSS columns & sticky rows stick. Breakpoints aren't exported, nor is anything which only changes how cells are painted or interacted with.
+    /// The default spacing of egui is used in place of the ui's.
+    /// Mirrored & right-to-left grids reverse the direction of their flexboxes, keeping cells in the order they were allocated in the HTML,
+    /// while [`Orientation::Auto`] can't know the space it'll be given, so it's exported as rows.
+    ///
+    /// The grid fills whatever element it's put in, so give that a size.
+    ///
+    /// ## Example
+    /// ```
+    /// # use egui_grid::GridBuilder;
+    /// # use egui_extras::Size;
+    /// let html = GridBuilder::new()
+    ///     .new_row(Size::exact(40.0)).cell(Size::remainder()).named("toolbar")
+    ///     .new_row(Size::remainder())
+    ///     .cell(Size::relative(0.25)).named("sidebar")
+    ///     .cell(Size::remainder()).named("content")
+    ///     .to_html();
+    ///
+    /// assert!(html.contains(r#"data-name="sidebar" style="flex: 0 0 25%"#));
+    ///
+    /// // Right-to-left rows are reversed, so the sidebar ends up on the right
+    /// let html = GridBuilder::new()
+    ///     .new_row(Size::remainder())
+    ///     .cell(Size::relative(0.25)).named("sidebar")
+    ///     .cell(Size::remainder()).named("content")
+    ///     .right_to_left(true)
+    ///     .to_html();
+    ///
+    /// assert!(html.find(r#"data-name="sidebar""#) < html.find(r#"data-name="content""#));
+    /// assert!(html.contains(r#"class="egui-grid-row" style="flex: 1 1 0; min-height: 0px; max-height: none; display: flex; flex-direction: row-reverse;"#));
+    /// assert!(!html.contains("direction: rtl"));
+    /// ```
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        self.write_html(&mut html, 0, (false, false), false);
+        html
+    }
+
+    // Nested grids are mirrored along with the grid holding them, so mirroring twice undoes it like it does for the cells of to_real_cells
+    fn write_html(&self, html: &mut String, depth: usize, mirrored: (bool, bool), rtl: bool) {
+        let indent = "  ".repeat(depth);
+        let columns = self.orientation == Orientation::Columns;
+        let spacing = if self.use_default_spacing {
+            Style::default().spacing.item_spacing
+        } else {
+            self.spacing
+        };
+        // The axes rows & cells are laid out on
+        let (row_axis, cell_axis) = if columns {
+            ("width", "height")
+        } else {
+            ("height", "width")
+        };
+        let mirror_x = mirrored.0 ^ (self.mirror_x || self.rtl);
+        let mirror_y = mirrored.1 ^ self.mirror_y;
+        let rtl = rtl ^ self.rtl;
+        let (grid_direction, row_direction) = if columns {
+            (direction(true, mirror_x), direction(false, mirror_y))
+        } else {
+            (direction(false, mirror_y), direction(true, mirror_x))
+        };
+
+        let _ = writeln!(
+            html,
+            r#"{indent}<div class="egui-grid" style="display: flex; flex-direction: {grid_direction}; gap: {}px {}px; width: 100%; height: 100%; box-sizing: border-box;">"#,
+            spacing.y, spacing.x,
+        );
+        for row in self.units.iter().filter(|row| row.visible) {
+            let mut style = flex(row.size, row_axis);
+            style.push_str(&format!(
+                " display: flex; flex-direction: {row_direction}; gap: {}px {}px;",
+                spacing.y, spacing.x
+            ));
+            match (&row.flow, &row.masonry) {
+                (Some(_), _) => {
+                    // Flows are as tall as the rows they wrap onto
+                    style = format!(
+                        "flex: 0 0 auto; display: flex; flex-direction: {row_direction}; flex-wrap: wrap; gap: {}px {}px;",
+                        spacing.y, spacing.x
+                    );
+                }
+                (_, Some(masonry)) => {
+                    // Masonries are as tall as their content
+                    style = match masonry.columns {
+                        MasonryColumns::Fixed(columns) => {
+                            format!("flex: 0 0 auto; column-count: {columns};")
+                        }
+                        MasonryColumns::Auto(min_width) => {
+                            format!("flex: 0 0 auto; column-width: {min_width}px;")
+                        }
+                    };
+                    style.push_str(&format!(" column-gap: {}px;", spacing.x));
+                }
+                _ => {
+                    style.push_str(&format!(" justify-content: {};", align(row.align)));
+                }
+            }
+            if row.sticky {
+                style.push_str(&format!(
+                    " position: sticky; {}: 0; z-index: 1;",
+                    if columns { "left" } else { "top" }
+                ));
+            }
+            let _ = writeln!(
+                html,
+                r#"{indent}  <div class="egui-grid-row" style="{style}">"#
+            );
+
+            let cells: Vec<(&Cell, String)> = match (&row.flow, &row.masonry) {
+                (Some(flow), _) => {
+                    let mut size = format!(
+                        "flex: 1 1 {}px; max-{cell_axis}: {};",
+                        flow.width.min,
+                        max(flow.width.max)
+                    );
+                    if let Size::Absolute { initial, .. } = row.size {
+                        size.push_str(&format!(" {row_axis}: {initial}px;"));
+                    }
+                    // A flow without a cell to copy (see Row::resolve) has nothing to show
+                    match row.cells.first() {
+                        Some(cell) => vec![(cell, size); flow.amount],
+                        None => Vec::new(),
+                    }
+                }
+                (_, Some(_)) => row
+                    .cells
+                    .iter()
+                    .map(|cell| {
+                        let height = match cell.size {
+                            Size::Absolute { initial, .. } => format!("height: {initial}px;"),
+                            Size::Relative { fraction, .. } => {
+                                format!("aspect-ratio: 1 / {fraction};")
+                            }
+                            Size::Remainder { .. } => String::new(),
+                        };
+                        let gap = format!(" margin-bottom: {}px; break-inside: avoid;", spacing.y);
+                        (cell, height + &gap)
+                    })
+                    .collect(),
+                _ => row
+                    .cells
+                    .iter()
+                    .map(|cell| (cell, flex(cell.size, cell_axis)))
+                    .collect(),
+            };
+            for (cell, size) in cells.into_iter().filter(|(cell, _)| cell.visible) {
+                let margin = cell.margin;
+                let layout = if rtl {
+                    mirror_layout(cell.layout)
+                } else {
+                    cell.layout
+                };
+                let mut style = format!(
+                    "{size} display: flex; flex-direction: {}; flex-wrap: {}; justify-content: {}; align-items: {}; padding: {}px {}px {}px {}px; box-sizing: border-box;",
+                    match layout.main_dir {
+                        Direction::LeftToRight => "row",
+                        Direction::RightToLeft => "row-reverse",
+                        Direction::TopDown => "column",
+                        Direction::BottomUp => "column-reverse",
+                    },
+                    if layout.main_wrap { "wrap" } else { "nowrap" },
+                    // egui only aligns along the main axis when it's justified
+                    if layout.main_justify {
+                        align(layout.main_align)
+                    } else {
+                        "flex-start"
+                    },
+                    if layout.cross_justify { "stretch" } else { align(layout.cross_align) },
+                    margin.top,
+                    margin.right,
+                    margin.bottom,
+                    margin.left,
+                );
+                if self.clip {
+                    style.push_str(" overflow: hidden;");
+                }
+                let name = match &cell.name {
+                    Some(name) => format!(r#" data-name="{}""#, escape(name)),
+                    None => String::new(),
+                };
+                match &cell.group {
+                    Some(grid) => {
+                        let _ = writeln!(
+                            html,
+                            r#"{indent}    <div class="egui-grid-cell"{name} style="{style}">"#
+                        );
+                        grid.write_html(html, depth + 3, (mirror_x, mirror_y), rtl);
+                        let _ = writeln!(html, "{indent}    </div>");
+                    }
+                    None => {
+                        let _ = writeln!(
+                            html,
+                            r#"{indent}    <div class="egui-grid-cell"{name} style="{style}"></div>"#
+                        );
+                    }
+                }
+            }
+            let _ = writeln!(html, "{indent}  </div>");
+        }
+        let _ = writeln!(html, "{indent}</div>");
+    }
+}
+
+// How much room something takes up along the given axis of the flexbox it's in
+fn flex(size: Size, axis: &str) -> String {
+    match size {
+        Size::Absolute { initial, .. } => format!("flex: 0 0 {initial}px;"),
+        Size::Relative { fraction, range } => format!(
+            "flex: 0 0 {}%; min-{axis}: {}px; max-{axis}: {};",
+            fraction * 100.,
+            range.min,
+            max(range.max)
+        ),
+        Size::Remainder { range } => format!(
+            "flex: 1 1 0; min-{axis}: {}px; max-{axis}: {};",
+            range.min,
+            max(range.max)
+        ),
+    }
+}
+
+fn max(length: f32) -> String {
+    if length.is_finite() {
+        format!("{length}px")
+    } else {
+        "none".to_string()
+    }
+}
+
+fn direction(row: bool, reverse: bool) -> &'static str {
+    match (row, reverse) {
+        (true, false) => "row",
+        (true, true) => "row-reverse",
+        (false, false) => "column",
+        (false, true) => "column-reverse",
+    }
+}
+
+fn align(align: Align) -> &'static str {
+    match align {
+        Align::Min => "flex-start",
+        Align::Center => "center",
+        Align::Max => "flex-end",
+    }
+}
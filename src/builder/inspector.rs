@@ -0,0 +1,500 @@
+// Keeps track of the grids shown each frame for the inspector, along with any edits made in it
+
+use super::*;
+use crate::debug::describe;
+use egui::{collapsing_header::CollapsingState, Color32, ComboBox, DragValue, LayerId, Window};
+use std::collections::HashMap;
+
+// Where the state of the inspector is kept in egui's memory
+fn key() -> Id {
+    Id::new("egui_grid_inspector")
+}
+
+// Where the last frame the inspector was shown in is kept, apart from the rest so grids can check it cheaply
+fn shown_key() -> Id {
+    key().with("shown")
+}
+
+#[derive(Clone, Default)]
+struct State {
+    // The frame the grids were recorded in
+    frame: u64,
+    grids: Vec<Record>,
+    // The grid & path of the row or cell picked in the tree
+    selected: Option<(Id, Vec<usize>)>,
+    edits: HashMap<Id, Vec<Edit>>,
+}
+
+// A grid as it was shown
+#[derive(Clone)]
+struct Record {
+    id: Id,
+    rect: Rect,
+    layout: GridBuilder,
+    // The path, outer rect & rect of each cell
+    cells: Vec<(Vec<usize>, Rect, Rect)>,
+}
+
+// A change made in the inspector, to the row (odd length path) or cell (even length path) found along `path`
+#[derive(Clone)]
+struct Edit {
+    path: Vec<usize>,
+    size: Option<Size>,
+    margin: Option<Margin>,
+}
+
+impl GridBuilder {
+    /// Show a window listing every grid shown this frame, for working out why a layout isn't behaving.
+    ///
+    /// Each grid is listed as a tree of its rows, cells & nested grids, along with their sizes & the rects they were given.
+    /// Picking a row or cell outlines it on screen, and lets its size (and a cell's margins) be changed.
+    /// Changes apply from the next frame on, until they're reset, so layouts can be tweaked without rebuilding.
+    ///
+    /// Grids are only kept track of while the window is open. Call this after the grids have been shown (or before any of them are),
+    /// otherwise only some will be listed. Changes to a grid with breakpoints go to whichever layout was in use.
+    /// Changes are kept by the grid's id, so grids which come & go need an id of their own to keep them (see [`Self::id_source`]).
+    ///
+    /// ## Example
+    /// ```
+    /// # use egui_grid::GridBuilder;
+    /// # use egui_extras::Size;
+    /// # fn demo(ctx: &egui::Context, inspecting: &mut bool) {
+    /// egui::CentralPanel::default().show(ctx, |ui| {
+    ///     GridBuilder::new()
+    ///         .new_row(Size::remainder())
+    ///         .cell(Size::relative(0.3)).named("sidebar")
+    ///         .cell(Size::remainder()).named("content")
+    ///         .show(ui, |mut grid| {
+    ///             grid.named("sidebar", |ui| {
+    ///                 ui.checkbox(inspecting, "Inspect");
+    ///             });
+    ///             grid.named("content", |ui| {
+    ///                 ui.label("Content");
+    ///             });
+    ///         });
+    /// });
+    /// GridBuilder::show_inspector(ctx, inspecting);
+    /// # }
+    /// ```
+    pub fn show_inspector(ctx: &Context, open: &mut bool) {
+        if !*open {
+            return;
+        }
+        let frame = ctx.frame_nr();
+        let (grids, mut selected, opened) = ctx.data_mut(|data| {
+            let opened = data
+                .get_temp::<u64>(shown_key())
+                .map_or(true, |shown| shown + 1 < frame);
+            data.insert_temp(shown_key(), frame);
+            let state = data.get_temp_mut_or_default::<State>(key());
+            (state.grids.clone(), state.selected.clone(), opened)
+        });
+        // Grids start being kept track of once the window has been opened
+        if opened {
+            ctx.request_repaint();
+        }
+        let mut edits = Vec::new();
+        let mut reset = false;
+        let mut hovered = None;
+
+        Window::new("Grid inspector")
+            .open(open)
+            .default_width(320.)
+            .show(ctx, |ui| {
+                egui::ScrollArea::vertical()
+                    .max_height(ui.available_height() * 0.6)
+                    .show(ui, |ui| {
+                        if grids.is_empty() {
+                            ui.weak("No grids shown");
+                        }
+                        for (index, record) in grids.iter().enumerate() {
+                            let header = CollapsingState::load_with_default_open(
+                                ctx,
+                                ui.make_persistent_id((record.id, index)),
+                                true,
+                            );
+                            header
+                                .show_header(ui, |ui| {
+                                    let text =
+                                        format!("Grid {:?}: {}", record.id, extent(record.rect));
+                                    node(ui, record, &[], text, &mut selected, &mut hovered);
+                                })
+                                .body(|ui| {
+                                    tree(
+                                        ui,
+                                        record,
+                                        &record.layout,
+                                        &mut Vec::new(),
+                                        &mut selected,
+                                        &mut hovered,
+                                    );
+                                });
+                        }
+                    });
+
+                ui.separator();
+                let picked = selected.as_ref().and_then(|(id, path)| {
+                    grids
+                        .iter()
+                        .find(|record| record.id == *id)
+                        .map(|record| (record, path))
+                });
+                match picked {
+                    Some((record, path)) => {
+                        if let Some(edit) = editor(ui, &record.layout, path) {
+                            edits.push((record.id, edit));
+                        }
+                    }
+                    None => {
+                        ui.weak("Pick a row or cell to change it");
+                    }
+                }
+                ui.separator();
+                reset = ui.button("Reset changes").clicked();
+            });
+
+        // Outline what's picked, and what's hovered over in the tree
+        let painter = ctx.layer_painter(LayerId::debug());
+        for ((id, path), color) in [
+            (selected.clone(), Color32::from_rgb(255, 200, 0)),
+            (hovered, Color32::from_rgb(0, 200, 255)),
+        ]
+        .into_iter()
+        .filter_map(|(picked, color)| picked.map(|picked| (picked, color)))
+        {
+            for record in grids.iter().filter(|record| record.id == id) {
+                let area = area(record, &path);
+                if area.is_positive() {
+                    painter.rect(area, 0., color.gamma_multiply(0.15), Stroke::new(2., color));
+                }
+            }
+        }
+
+        ctx.data_mut(|data| {
+            let state = data.get_temp_mut_or_default::<State>(key());
+            state.selected = selected;
+            if reset {
+                state.edits.clear();
+            }
+            for (id, edit) in edits {
+                let grid = state.edits.entry(id).or_default();
+                match grid.iter_mut().find(|other| other.path == edit.path) {
+                    Some(other) => {
+                        other.size = edit.size.or(other.size);
+                        other.margin = edit.margin.or(other.margin);
+                    }
+                    None => grid.push(edit),
+                }
+            }
+        });
+    }
+
+    // Apply any changes made in the inspector, returning whether the grid should be kept track of
+    pub(crate) fn inspect(&mut self, ctx: &Context, id: Id, rect: Rect) -> bool {
+        // Until the inspector has been opened there's nothing to apply, so leave egui's memory be
+        let Some(shown) = ctx.data(|data| data.get_temp::<u64>(shown_key())) else {
+            return false;
+        };
+        ctx.data_mut(|data| {
+            if let Some(edits) = data.get_temp_mut_or_default::<State>(key()).edits.get(&id) {
+                let layout = self.active_mut(rect);
+                for edit in edits {
+                    layout.apply(&edit.path, edit);
+                }
+            }
+        });
+        shown + 1 >= ctx.frame_nr()
+    }
+
+    // Keep track of how the grid was laid out this frame
+    pub(crate) fn record(&self, ctx: &Context, id: Id, rect: Rect, cells: &[PureCell]) {
+        let record = Record {
+            id,
+            rect,
+            layout: self.active(rect).clone(),
+            cells: cells
+                .iter()
+                .map(|cell| (cell.path().to_vec(), cell.outer(), cell.rect()))
+                .collect(),
+        };
+        let frame = ctx.frame_nr();
+        ctx.data_mut(|data| {
+            let state = data.get_temp_mut_or_default::<State>(key());
+            if state.frame != frame {
+                state.frame = frame;
+                state.grids.clear();
+            }
+            state.grids.push(record);
+        });
+    }
+
+    // Anything no longer in the layout is skipped
+    fn apply(&mut self, path: &[usize], edit: &Edit) {
+        match path {
+            [row] => {
+                if let (Some(row), Some(size)) = (self.units.get_mut(*row), edit.size) {
+                    row.size = size;
+                }
+            }
+            [row, cell, nested @ ..] => {
+                let Some(cell) = self
+                    .units
+                    .get_mut(*row)
+                    .and_then(|row| row.cells.get_mut(*cell))
+                else {
+                    return;
+                };
+                if !nested.is_empty() {
+                    if let Some(grid) = &mut cell.group {
+                        grid.apply(nested, edit);
+                    }
+                    return;
+                }
+                if let Some(size) = edit.size {
+                    cell.size = size;
+                }
+                if let Some(margin) = edit.margin {
+                    cell.margin = margin;
+                }
+            }
+            [] => {}
+        }
+    }
+}
+
+// List the rows & cells of a grid, and those of any grids nested within
+fn tree(
+    ui: &mut Ui,
+    record: &Record,
+    layout: &GridBuilder,
+    path: &mut Vec<usize>,
+    selected: &mut Option<(Id, Vec<usize>)>,
+    hovered: &mut Option<(Id, Vec<usize>)>,
+) {
+    for (row_index, row) in layout.units.iter().enumerate() {
+        path.push(row_index);
+        let mut text = format!("Row {}: {}", row_index, describe(row.size));
+        if row.flow.is_some() {
+            text.push_str(" (flow)");
+        } else if row.masonry.is_some() {
+            text.push_str(" (masonry)");
+        }
+        text.push_str(&shown(record, path));
+        CollapsingState::load_with_default_open(
+            ui.ctx(),
+            ui.make_persistent_id((record.id, &path)),
+            false,
+        )
+        .show_header(ui, |ui| node(ui, record, path, text, selected, hovered))
+        .body(|ui| {
+            for (cell_index, cell) in row.cells.iter().enumerate() {
+                path.push(cell_index);
+                let mut text = format!("Cell {}", cell_index);
+                if let Some(name) = &cell.name {
+                    text.push_str(&format!(" \"{}\"", name));
+                }
+                text.push_str(&format!(": {}", describe(cell.size)));
+                text.push_str(&shown(record, path));
+                match &cell.group {
+                    Some(grid) => {
+                        CollapsingState::load_with_default_open(
+                            ui.ctx(),
+                            ui.make_persistent_id((record.id, &path)),
+                            false,
+                        )
+                        .show_header(ui, |ui| node(ui, record, path, text, selected, hovered))
+                        .body(|ui| tree(ui, record, grid, path, selected, hovered));
+                    }
+                    None => node(ui, record, path, text, selected, hovered),
+                }
+                path.pop();
+            }
+        });
+        path.pop();
+    }
+}
+
+// A row or cell which can be picked
+fn node(
+    ui: &mut Ui,
+    record: &Record,
+    path: &[usize],
+    text: String,
+    selected: &mut Option<(Id, Vec<usize>)>,
+    hovered: &mut Option<(Id, Vec<usize>)>,
+) {
+    let picked = selected
+        .as_ref()
+        .is_some_and(|(id, other)| *id == record.id && other == path);
+    let response = ui.selectable_label(picked, text);
+    if response.clicked() {
+        *selected = if picked {
+            None
+        } else {
+            Some((record.id, path.to_vec()))
+        };
+    }
+    if response.hovered() {
+        *hovered = Some((record.id, path.to_vec()));
+    }
+}
+
+// Change the size of the row or cell at `path`, and the margin of a cell
+fn editor(ui: &mut Ui, layout: &GridBuilder, path: &[usize]) -> Option<Edit> {
+    let mut layout = layout;
+    let mut rest = path;
+    while rest.len() > 2 {
+        layout = layout
+            .units
+            .get(rest[0])?
+            .cells
+            .get(rest[1])?
+            .group
+            .as_ref()?;
+        rest = &rest[2..];
+    }
+    let mut edit = Edit {
+        path: path.to_vec(),
+        size: None,
+        margin: None,
+    };
+    match *rest {
+        [row] => {
+            let mut size = layout.units.get(row)?.size;
+            ui.label(format!("Row {}", row));
+            if size_editor(ui, ui.id().with(path), &mut size) {
+                edit.size = Some(size);
+            }
+        }
+        [row, cell] => {
+            let cell_ref = layout.units.get(row)?.cells.get(cell)?;
+            let (mut size, mut margin) = (cell_ref.size, cell_ref.margin);
+            ui.label(format!("Cell {} of row {}", cell, row));
+            if size_editor(ui, ui.id().with(path), &mut size) {
+                edit.size = Some(size);
+            }
+            let mut changed = false;
+            egui::Grid::new(ui.id().with((path, "margin"))).show(ui, |ui| {
+                for (label, value) in [
+                    ("Left", &mut margin.left),
+                    ("Right", &mut margin.right),
+                    ("Top", &mut margin.top),
+                    ("Bottom", &mut margin.bottom),
+                ] {
+                    ui.label(label);
+                    changed |= ui.add(DragValue::new(value).speed(0.5)).changed();
+                    ui.end_row();
+                }
+            });
+            if changed {
+                edit.margin = Some(margin);
+            }
+        }
+        _ => return None,
+    }
+    (edit.size.is_some() || edit.margin.is_some()).then_some(edit)
+}
+
+fn size_editor(ui: &mut Ui, id: Id, size: &mut Size) -> bool {
+    const KINDS: [&str; 3] = ["Exact", "Relative", "Remainder"];
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        ui.label("Size");
+        let kind = match size {
+            Size::Absolute { .. } => 0,
+            Size::Relative { .. } => 1,
+            Size::Remainder { .. } => 2,
+        };
+        let mut picked = kind;
+        ComboBox::from_id_source(id)
+            .selected_text(KINDS[kind])
+            .show_ui(ui, |ui| {
+                for (index, name) in KINDS.iter().enumerate() {
+                    ui.selectable_value(&mut picked, index, *name);
+                }
+            });
+        if picked != kind {
+            *size = match picked {
+                0 => Size::exact(100.),
+                1 => Size::relative(0.5),
+                _ => Size::remainder(),
+            };
+            changed = true;
+        }
+        match size {
+            Size::Absolute { initial, .. } => {
+                let mut points = *initial;
+                if ui
+                    .add(
+                        DragValue::new(&mut points)
+                            .range(0.0..=f32::INFINITY)
+                            .suffix(" px"),
+                    )
+                    .changed()
+                {
+                    *size = Size::exact(points);
+                    changed = true;
+                }
+            }
+            Size::Relative { fraction, .. } => {
+                changed |= ui
+                    .add(DragValue::new(fraction).range(0.0..=1.0).speed(0.005))
+                    .changed();
+            }
+            Size::Remainder { .. } => {}
+        }
+    });
+    changed
+}
+
+// Everything taken up by the row or cell at `path`, the whole grid for an empty path
+fn area(record: &Record, path: &[usize]) -> Rect {
+    if path.is_empty() {
+        return record.rect;
+    }
+    record
+        .cells
+        .iter()
+        .filter(|(other, _, _)| other.starts_with(path))
+        .fold(Rect::NOTHING, |area, (_, outer, _)| area.union(*outer))
+}
+
+fn shown(record: &Record, path: &[usize]) -> String {
+    let area = area(record, path);
+    if area.is_positive() {
+        format!(", {}", extent(area))
+    } else {
+        ", not shown".to_string()
+    }
+}
+
+fn extent(rect: Rect) -> String {
+    format!(
+        "{:.0}×{:.0} at ({:.0}, {:.0})",
+        rect.width(),
+        rect.height(),
+        rect.min.x,
+        rect.min.y
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grids_leave_memory_alone_until_inspected() {
+        let ctx = Context::default();
+        let rect = Rect::from_min_size(Pos2::ZERO, Vec2::splat(100.));
+        let mut grid = GridBuilder::new()
+            .new_row(Size::remainder())
+            .cell(Size::remainder());
+        assert!(!grid.inspect(&ctx, Id::new("grid"), rect));
+        assert!(ctx.data(|data| data.get_temp::<State>(key()).is_none()));
+
+        let _ = ctx.run(Default::default(), |ctx| {
+            GridBuilder::show_inspector(ctx, &mut true);
+        });
+        assert!(grid.inspect(&ctx, Id::new("grid"), rect));
+    }
+}
@@ -0,0 +1,302 @@
+// Counterparts to the builder's methods taking `&mut self`, for building layouts out of data
+
+use super::*;
+
+impl GridBuilder {
+    /// Build a grid out of rows, each given as its size along with the sizes of its cells.
+    ///
+    /// ## Example
+    /// ```
+    /// # use egui_grid::GridBuilder;
+    /// # use egui_extras::Size;
+    /// # fn demo(ui: &mut egui::Ui, columns: &[f32]) {
+    /// // A header row, then 10 rows with a cell for each column
+    /// let grid = GridBuilder::from_rows(
+    ///     std::iter::once((Size::exact(24.0), vec![Size::remainder()])).chain(
+    ///         (0..10).map(|_| (Size::exact(20.0), columns.iter().map(|width| Size::exact(*width)).collect())),
+    ///     ),
+    /// );
+    /// # }
+    /// ```
+    pub fn from_rows<C: IntoIterator<Item = Size>>(
+        rows: impl IntoIterator<Item = (Size, C)>,
+    ) -> GridBuilder {
+        let mut grid = GridBuilder::new();
+        for (size, cells) in rows {
+            grid.push_row(size).push_cells(cells);
+        }
+        grid
+    }
+
+    /// Allocate a new row, like [`Self::new_row`] but without taking the builder, for building grids in loops.
+    ///
+    /// Returns the builder again, so calls can still be chained.
+    ///
+    /// ## Example
+    /// ```
+    /// # use egui_grid::GridBuilder;
+    /// # use egui_extras::Size;
+    /// # use egui::Margin;
+    /// # struct Model { sections: Vec<Vec<f32>> }
+    /// # fn demo(ui: &mut egui::Ui, model: &Model) {
+    /// let mut grid = GridBuilder::new();
+    /// for section in &model.sections {
+    ///     grid.push_row(Size::exact(40.0));
+    ///     for width in section {
+    ///         grid.push_cell(Size::exact(*width));
+    ///     }
+    /// }
+    /// // Change a cell after the fact
+    /// if let Some(mut cell) = grid.cell_mut(0, 0) {
+    ///     cell.margin(Margin::same(4.0)).named("first");
+    /// }
+    /// grid.show(ui, |mut grid| {
+    ///     // ..
+    /// });
+    /// # }
+    /// ```
+    pub fn push_row(&mut self, size: Size) -> &mut Self {
+        self.units.push(Row::new(size, Align::Min));
+        self
+    }
+
+    /// Add a cell to the most recently allocated row, like [`Self::cell`].
+    /// Does nothing unless at least one row has been allocated.
+    pub fn push_cell(&mut self, size: Size) -> &mut Self {
+        self.add_cells(size, 1, Margin::same(0.));
+        self
+    }
+
+    /// Add a cell of each size to the most recently allocated row.
+    /// Does nothing unless at least one row has been allocated.
+    pub fn push_cells(&mut self, sizes: impl IntoIterator<Item = Size>) -> &mut Self {
+        for size in sizes {
+            self.add_cells(size, 1, Margin::same(0.));
+        }
+        self
+    }
+
+    /// Allocate a new row at `index`, moving the rows after it along by one. Fill it using [`Self::insert_cell`].
+    ///
+    /// Panics if `index` is past the number of rows.
+    pub fn insert_row(&mut self, index: usize, size: Size) -> &mut Self {
+        self.units.insert(index, Row::new(size, Align::Min));
+        for (row, _) in self.creation_cache.iter_mut() {
+            if *row >= index {
+                *row += 1;
+            }
+        }
+        self
+    }
+
+    /// Remove the row at `index` along with its cells, moving the rows after it back by one.
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove_row(&mut self, index: usize) -> &mut Self {
+        self.units.remove(index);
+        self.creation_cache.retain(|(row, _)| *row != index);
+        for (row, _) in self.creation_cache.iter_mut() {
+            if *row > index {
+                *row -= 1;
+            }
+        }
+        self
+    }
+
+    /// Add a cell to the row at `row`, at `index` within the row. Does nothing for a flow.
+    ///
+    /// Panics if either `row` or `index` is out of bounds.
+    pub fn insert_cell(&mut self, row: usize, index: usize, size: Size) -> &mut Self {
+        if self.units[row].flow.is_none() {
+            self.units[row].cells.insert(
+                index,
+                Cell::new(size, Margin::same(0.), self.default_layout),
+            );
+            self.creation_cache = vec![(row, index)];
+        }
+        self
+    }
+
+    /// Remove the cell at `index` within the row at `row`. Does nothing for a flow, as its cell is copied to make up the flow.
+    ///
+    /// Panics if either `row` or `index` is out of bounds.
+    pub fn remove_cell(&mut self, row: usize, index: usize) -> &mut Self {
+        if self.units[row].flow.is_some() {
+            return self;
+        }
+        self.units[row].cells.remove(index);
+        self.creation_cache.retain(|item| *item != (row, index));
+        for (other, cell) in self.creation_cache.iter_mut() {
+            if *other == row && *cell > index {
+                *cell -= 1;
+            }
+        }
+        self
+    }
+
+    /// Change the cell at `index` within the row at `row`, if there is one.
+    pub fn cell_mut(&mut self, row: usize, index: usize) -> Option<CellMut<'_>> {
+        self.units
+            .get_mut(row)?
+            .cells
+            .get_mut(index)
+            .map(|cell| CellMut { cell })
+    }
+
+    /// How many rows have been allocated.
+    pub fn row_count(&self) -> usize {
+        self.units.len()
+    }
+
+    /// How many cells have been added to the row at `row`, if there is one.
+    pub fn cell_count(&self, row: usize) -> Option<usize> {
+        self.units.get(row).map(|row| row.cells.len())
+    }
+}
+
+/// A cell of a [`GridBuilder`], borrowed using [`GridBuilder::cell_mut`] to change it.
+///
+/// Each method matches the builder method for the most recently allocated cells.
+pub struct CellMut<'a> {
+    cell: &'a mut Cell,
+}
+
+impl CellMut<'_> {
+    /// Change the cell's size.
+    pub fn size(&mut self, size: Size) -> &mut Self {
+        self.cell.size = size;
+        self
+    }
+
+    /// See [`GridBuilder::with_margin`].
+    pub fn margin(&mut self, margin: Margin) -> &mut Self {
+        self.cell.edit_margin(margin);
+        self
+    }
+
+    /// See [`GridBuilder::with_layout`].
+    pub fn layout(&mut self, layout: Layout) -> &mut Self {
+        self.cell.edit_layout(layout);
+        self
+    }
+
+    /// See [`GridBuilder::with_frame`].
+    pub fn frame(&mut self, frame: impl Into<CellFrame>) -> &mut Self {
+        self.cell.frame = Some(frame.into());
+        self
+    }
+
+    /// See [`GridBuilder::with_sense`].
+    pub fn sense(&mut self, sense: Sense) -> &mut Self {
+        self.cell.sense = sense;
+        self
+    }
+
+    /// See [`GridBuilder::with_collapsible`].
+    pub fn collapsible(&mut self, size: f32) -> &mut Self {
+        self.cell.collapse = Some(Collapse::new(size));
+        self
+    }
+
+    /// See [`GridBuilder::visible`].
+    pub fn visible(&mut self, visible: bool) -> &mut Self {
+        self.cell.visible = visible;
+        self
+    }
+
+    /// See [`GridBuilder::named`].
+    pub fn named(&mut self, name: impl Into<String>) -> &mut Self {
+        self.cell.name = Some(name.into());
+        self
+    }
+
+    /// See [`GridBuilder::nest`].
+    pub fn nest(&mut self, grid: GridBuilder) -> &mut Self {
+        self.cell.nest(grid);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Two rows of three cells, with the last cell being the most recently allocated
+    fn two_rows() -> GridBuilder {
+        let mut grid = GridBuilder::new();
+        for _ in 0..2 {
+            grid.push_row(Size::exact(20.)).push_cells([
+                Size::exact(10.),
+                Size::exact(20.),
+                Size::exact(30.),
+            ]);
+        }
+        grid
+    }
+
+    // Which cells were given a margin
+    fn margined(grid: GridBuilder) -> Vec<(usize, usize)> {
+        let grid = grid.with_margin(Margin::same(4.));
+        let mut found = Vec::new();
+        for (row_index, row) in grid.units.iter().enumerate() {
+            for (cell_index, cell) in row.cells.iter().enumerate() {
+                if cell.margin != Margin::same(0.) {
+                    found.push((row_index, cell_index));
+                }
+            }
+        }
+        found
+    }
+
+    #[test]
+    fn inserting_rows_keeps_track_of_the_latest_cells() {
+        let mut grid = two_rows();
+        grid.insert_row(0, Size::exact(5.));
+        assert_eq!(margined(grid), vec![(2, 2)]);
+
+        // Rows inserted after it don't move it
+        let mut grid = two_rows();
+        grid.insert_row(2, Size::exact(5.));
+        assert_eq!(margined(grid), vec![(1, 2)]);
+    }
+
+    #[test]
+    fn removing_rows_keeps_track_of_the_latest_cells() {
+        let mut grid = two_rows();
+        grid.remove_row(0);
+        assert_eq!(margined(grid), vec![(0, 2)]);
+
+        // Removing the row they're in leaves nothing to change
+        let mut grid = two_rows();
+        grid.remove_row(1);
+        assert_eq!(margined(grid), vec![]);
+    }
+
+    #[test]
+    fn removing_cells_keeps_track_of_the_latest_cells() {
+        let mut grid = two_rows();
+        grid.remove_cell(1, 0);
+        assert_eq!(margined(grid), vec![(1, 1)]);
+
+        // Cells in other rows, or after it, don't move it
+        let mut grid = two_rows();
+        grid.remove_cell(0, 0);
+        assert_eq!(margined(grid), vec![(1, 2)]);
+
+        let mut grid = two_rows();
+        grid.remove_cell(1, 2);
+        assert_eq!(margined(grid), vec![]);
+
+        // Inserted cells become the latest
+        let mut grid = two_rows();
+        grid.insert_cell(0, 1, Size::exact(5.));
+        assert_eq!(margined(grid), vec![(0, 1)]);
+    }
+
+    #[test]
+    fn flows_keep_their_cell() {
+        let mut grid = GridBuilder::new().new_flow(Size::exact(20.), 50., 100., 4);
+        grid.remove_cell(0, 0).insert_cell(0, 0, Size::exact(10.));
+        assert_eq!(grid.cell_count(0), Some(1));
+    }
+}
@@ -0,0 +1,492 @@
+// The serialized form of a GridBuilder, kept separate so the builder can change without breaking saved layouts.
+// Anything added here has to have a default, so layouts saved by older versions still load. Anything changed or removed bumps VERSION.
+// Unknown fields are rejected, so typos in hand-written layouts aren't quietly ignored.
+
+use super::*;
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+// The version of the schema layouts are saved with
+const VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Versioned {
+    version: u32,
+    grid: GridDef,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct GridDef {
+    rows: Vec<RowDef>,
+    // Left out to use the ui's item spacing
+    spacing: Option<Vec2>,
+    orientation: Orientation,
+    clip: bool,
+    frozen_cells: usize,
+    id_source: Option<Id>,
+    breakpoints: Vec<(Breakpoint, GridDef)>,
+    mirror_horizontally: bool,
+    mirror_vertically: bool,
+    right_to_left: bool,
+    frame: Option<CellFrame>,
+    separators: Option<Separators>,
+    striped: bool,
+    highlight_rows: bool,
+    selection: Option<SelectionMode>,
+    select_rows: bool,
+    keyboard_navigation: bool,
+    reorder: Option<ReorderMode>,
+    animate: Option<f32>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct RowDef {
+    size: SizeDef,
+    align: Align,
+    cells: Vec<CellDef>,
+    sticky: bool,
+    flow: Option<FlowDef>,
+    masonry: Option<MasonryDef>,
+    frame: Option<CellFrame>,
+    collapsible: Option<f32>,
+    visible: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct CellDef {
+    size: SizeDef,
+    margin: Margin,
+    layout: LayoutDef,
+    grid: Option<GridDef>,
+    name: Option<String>,
+    sense: SenseDef,
+    frame: Option<CellFrame>,
+    collapsible: Option<f32>,
+    visible: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct LayoutDef {
+    main_dir: egui::Direction,
+    main_wrap: bool,
+    main_align: Align,
+    main_justify: bool,
+    cross_align: Align,
+    cross_justify: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FlowDef {
+    min_width: f32,
+    max_width: f32,
+    amount: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+enum MasonryDef {
+    Columns(usize),
+    MinWidth(f32),
+}
+
+// Sizes, with bounds left out when they're unbounded
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+enum SizeDef {
+    Exact(f32),
+    Absolute {
+        initial: f32,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min: Option<f32>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max: Option<f32>,
+    },
+    Relative {
+        fraction: f32,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min: Option<f32>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max: Option<f32>,
+    },
+    Remainder {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min: Option<f32>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max: Option<f32>,
+    },
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct SenseDef {
+    click: bool,
+    drag: bool,
+    focusable: bool,
+}
+
+impl Default for GridDef {
+    fn default() -> Self {
+        GridDef::from(&GridBuilder::default())
+    }
+}
+
+impl Default for RowDef {
+    fn default() -> Self {
+        RowDef::from(&Row::new(Size::remainder(), Align::Min))
+    }
+}
+
+impl Default for CellDef {
+    fn default() -> Self {
+        CellDef::from(&Cell::new(
+            Size::remainder(),
+            Margin::same(0.),
+            Layout::default(),
+        ))
+    }
+}
+
+impl Default for SenseDef {
+    fn default() -> Self {
+        SenseDef::from(Sense::hover())
+    }
+}
+
+impl Default for LayoutDef {
+    fn default() -> Self {
+        LayoutDef::from(Layout::default())
+    }
+}
+
+impl Default for SizeDef {
+    fn default() -> Self {
+        SizeDef::from(Size::remainder())
+    }
+}
+
+impl From<&GridBuilder> for GridDef {
+    fn from(grid: &GridBuilder) -> Self {
+        GridDef {
+            rows: grid.units.iter().map(RowDef::from).collect(),
+            spacing: (!grid.use_default_spacing).then_some(grid.spacing),
+            orientation: grid.orientation,
+            clip: grid.clip,
+            frozen_cells: grid.frozen,
+            id_source: grid.id_source,
+            breakpoints: grid
+                .breakpoints
+                .iter()
+                .map(|(breakpoint, layout)| (*breakpoint, GridDef::from(layout)))
+                .collect(),
+            mirror_horizontally: grid.mirror_x,
+            mirror_vertically: grid.mirror_y,
+            right_to_left: grid.rtl,
+            frame: grid.frame,
+            separators: grid.separators,
+            striped: grid.striped,
+            highlight_rows: grid.highlight_rows,
+            selection: grid.selection,
+            select_rows: grid.select_rows,
+            keyboard_navigation: grid.keyboard_navigation,
+            reorder: grid.reorder,
+            animate: grid.animate,
+        }
+    }
+}
+
+// Loading fails on anything the builder (or egui_extras) would panic on later, saying which row & cell it's in
+impl TryFrom<GridDef> for GridBuilder {
+    type Error = String;
+
+    fn try_from(grid: GridDef) -> Result<Self, String> {
+        Ok(GridBuilder {
+            units: grid
+                .rows
+                .into_iter()
+                .enumerate()
+                .map(|(index, row)| {
+                    Row::try_from(row).map_err(|error| format!("row {index}, {error}"))
+                })
+                .collect::<Result<_, _>>()?,
+            spacing: grid.spacing.unwrap_or(Vec2::ZERO),
+            use_default_spacing: grid.spacing.is_none(),
+            orientation: grid.orientation,
+            clip: grid.clip,
+            frozen: grid.frozen_cells,
+            id_source: grid.id_source,
+            breakpoints: grid
+                .breakpoints
+                .into_iter()
+                .enumerate()
+                .map(|(index, (breakpoint, layout))| {
+                    let layout = GridBuilder::try_from(layout)
+                        .map_err(|error| format!("breakpoint {index}, {error}"))?;
+                    Ok((breakpoint, layout))
+                })
+                .collect::<Result<_, String>>()?,
+            mirror_x: grid.mirror_horizontally,
+            mirror_y: grid.mirror_vertically,
+            rtl: grid.right_to_left,
+            frame: grid.frame,
+            separators: grid.separators,
+            striped: grid.striped,
+            highlight_rows: grid.highlight_rows,
+            selection: grid.selection,
+            select_rows: grid.select_rows,
+            keyboard_navigation: grid.keyboard_navigation,
+            reorder: grid.reorder,
+            animate: grid.animate,
+            ..Default::default()
+        })
+    }
+}
+
+impl From<&Row> for RowDef {
+    fn from(row: &Row) -> Self {
+        RowDef {
+            size: SizeDef::from(row.size),
+            align: row.align,
+            cells: row.cells.iter().map(CellDef::from).collect(),
+            sticky: row.sticky,
+            flow: row.flow.as_ref().map(|flow| FlowDef {
+                min_width: flow.width.min,
+                max_width: flow.width.max,
+                amount: flow.amount,
+            }),
+            masonry: row.masonry.as_ref().map(|masonry| match masonry.columns {
+                MasonryColumns::Fixed(columns) => MasonryDef::Columns(columns),
+                MasonryColumns::Auto(min_width) => MasonryDef::MinWidth(min_width),
+            }),
+            frame: row.frame,
+            collapsible: row.collapse.map(|collapse| collapse.size),
+            visible: row.visible,
+        }
+    }
+}
+
+impl TryFrom<RowDef> for Row {
+    type Error = String;
+
+    fn try_from(row: RowDef) -> Result<Self, String> {
+        let size = Size::try_from(row.size).map_err(|error| format!("size: {error}"))?;
+        let mut output = Row::new(size, row.align);
+        output.cells = row
+            .cells
+            .into_iter()
+            .enumerate()
+            .map(|(index, cell)| {
+                Cell::try_from(cell).map_err(|error| format!("cell {index}, {error}"))
+            })
+            .collect::<Result<_, _>>()?;
+        output.sticky = row.sticky;
+        output.flow = match row.flow {
+            Some(flow) => Some(Flow {
+                width: range(Some(flow.min_width), Some(flow.max_width))
+                    .map_err(|error| format!("flow width: {error}"))?,
+                amount: flow.amount,
+            }),
+            None => None,
+        };
+        // A flow needs a cell to use as a template
+        if output.flow.is_some() && output.cells.is_empty() {
+            output.cells.push(Cell::new(
+                Size::remainder(),
+                Margin::same(0.),
+                Layout::default(),
+            ));
+        }
+        output.masonry = match row.masonry {
+            Some(MasonryDef::Columns(0)) => {
+                return Err("masonry: there has to be at least 1 column".to_string())
+            }
+            Some(MasonryDef::Columns(columns)) => {
+                Some(Masonry::new(MasonryColumns::Fixed(columns)))
+            }
+            Some(MasonryDef::MinWidth(min_width)) => Some(Masonry::new(MasonryColumns::Auto(
+                finite(min_width).map_err(|error| format!("masonry min width: {error}"))?,
+            ))),
+            None => None,
+        };
+        output.frame = row.frame;
+        output.collapse = row.collapsible.map(Collapse::new);
+        output.visible = row.visible;
+        Ok(output)
+    }
+}
+
+impl From<&Cell> for CellDef {
+    fn from(cell: &Cell) -> Self {
+        CellDef {
+            size: SizeDef::from(cell.size),
+            margin: cell.margin,
+            layout: LayoutDef::from(cell.layout),
+            grid: cell.group.as_ref().map(GridDef::from),
+            name: cell.name.clone(),
+            sense: SenseDef::from(cell.sense),
+            frame: cell.frame,
+            collapsible: cell.collapse.map(|collapse| collapse.size),
+            visible: cell.visible,
+        }
+    }
+}
+
+impl TryFrom<CellDef> for Cell {
+    type Error = String;
+
+    fn try_from(cell: CellDef) -> Result<Self, String> {
+        let mut output = Cell::new(
+            Size::try_from(cell.size).map_err(|error| format!("size: {error}"))?,
+            cell.margin,
+            Layout::from(cell.layout),
+        );
+        output.group = cell.grid.map(GridBuilder::try_from).transpose()?;
+        output.name = cell.name;
+        output.sense = Sense::from(cell.sense);
+        output.frame = cell.frame;
+        output.collapse = cell.collapsible.map(Collapse::new);
+        output.visible = cell.visible;
+        Ok(output)
+    }
+}
+
+impl From<Size> for SizeDef {
+    fn from(size: Size) -> Self {
+        // A minimum of 0 & no maximum are what egui_extras uses by default
+        let min = |range: Rangef| (range.min != 0.).then_some(range.min);
+        let max = |range: Rangef| range.max.is_finite().then_some(range.max);
+        match size {
+            Size::Absolute { initial, range } if range.min == initial && range.max == initial => {
+                SizeDef::Exact(initial)
+            }
+            Size::Absolute { initial, range } => SizeDef::Absolute {
+                initial,
+                min: min(range),
+                max: max(range),
+            },
+            Size::Relative { fraction, range } => SizeDef::Relative {
+                fraction,
+                min: min(range),
+                max: max(range),
+            },
+            Size::Remainder { range } => SizeDef::Remainder {
+                min: min(range),
+                max: max(range),
+            },
+        }
+    }
+}
+
+impl TryFrom<SizeDef> for Size {
+    type Error = String;
+
+    fn try_from(size: SizeDef) -> Result<Self, String> {
+        let size = match size {
+            SizeDef::Exact(points) => Size::exact(finite(points)?),
+            SizeDef::Absolute { initial, min, max } => {
+                Size::initial(finite(initial)?).with_range(range(min, max)?)
+            }
+            SizeDef::Relative { fraction, min, max } => {
+                if !(0. ..=1.).contains(&fraction) {
+                    return Err(format!("fraction has to be from 0 to 1, not {fraction}"));
+                }
+                Size::relative(fraction).with_range(range(min, max)?)
+            }
+            SizeDef::Remainder { min, max } => Size::remainder().with_range(range(min, max)?),
+        };
+        Ok(size)
+    }
+}
+
+fn finite(value: f32) -> Result<f32, String> {
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err(format!("{value} isn't a finite number"))
+    }
+}
+
+// Bounds left out are 0 & unbounded, with an infinite max also meaning unbounded
+fn range(min: Option<f32>, max: Option<f32>) -> Result<Rangef, String> {
+    let min = finite(min.unwrap_or(0.))?;
+    let max = max.unwrap_or(f32::INFINITY);
+    if max.is_nan() {
+        return Err("NaN isn't a finite number".to_string());
+    }
+    if min > max {
+        return Err(format!("min ({min}) is more than max ({max})"));
+    }
+    Ok(Rangef::new(min, max))
+}
+
+impl From<Layout> for LayoutDef {
+    fn from(layout: Layout) -> Self {
+        LayoutDef {
+            main_dir: layout.main_dir,
+            main_wrap: layout.main_wrap,
+            main_align: layout.main_align,
+            main_justify: layout.main_justify,
+            cross_align: layout.cross_align,
+            cross_justify: layout.cross_justify,
+        }
+    }
+}
+
+impl From<LayoutDef> for Layout {
+    fn from(layout: LayoutDef) -> Self {
+        Layout {
+            main_dir: layout.main_dir,
+            main_wrap: layout.main_wrap,
+            main_align: layout.main_align,
+            main_justify: layout.main_justify,
+            cross_align: layout.cross_align,
+            cross_justify: layout.cross_justify,
+        }
+    }
+}
+
+impl From<Sense> for SenseDef {
+    fn from(sense: Sense) -> Self {
+        SenseDef {
+            click: sense.click,
+            drag: sense.drag,
+            focusable: sense.focusable,
+        }
+    }
+}
+
+impl From<SenseDef> for Sense {
+    fn from(sense: SenseDef) -> Self {
+        let mut output = Sense::hover();
+        output.click = sense.click;
+        output.drag = sense.drag;
+        output.focusable = sense.focusable;
+        output
+    }
+}
+
+impl Serialize for GridBuilder {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Versioned {
+            version: VERSION,
+            grid: GridDef::from(self),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for GridBuilder {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let layout = Versioned::deserialize(deserializer)?;
+        if layout.version > VERSION {
+            return Err(D::Error::custom(format!(
+                "layout was saved with version {} of the schema, only versions up to {} are supported",
+                layout.version, VERSION
+            )));
+        }
+        GridBuilder::try_from(layout.grid).map_err(D::Error::custom)
+    }
+}
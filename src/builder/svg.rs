@@ -0,0 +1,121 @@
+use super::*;
+use egui::Style;
+use std::fmt::Write;
+
+// Colors given to cells, going by how deeply they're nested
+const PALETTE: [&str; 6] = [
+    "#4e79a7", "#f28e2b", "#59a14f", "#e15759", "#76b7b2", "#b07aa1",
+];
+
+impl GridBuilder {
+    /// Draw the layout this grid would have in `rect` as an [SVG](https://developer.mozilla.org/en-US/docs/Web/SVG) image, for documenting layouts & reviewing changes to them.
+    ///
+    /// Runs the same layout as [`Self::show`], only without a ui, so it works anywhere (tests & build scripts included).
+    /// Sizes of [`Size::remainder`](https://docs.rs/egui_extras/latest/egui_extras/enum.Size.html#method.remainder) masonry cells can't be measured,
+    /// and the default spacing of egui is used in place of the ui's.
+    ///
+    /// The spacing between cells is grey, with cells colored by how deeply they're nested. Margins are painted lighter than the cells they surround,
+    /// and each cell is labeled with its index in the order used by [`Grid::cell`](crate::Grid::cell) along with its name.
+    ///
+    /// ## Example
+    /// ```
+    /// # use egui_grid::GridBuilder;
+    /// # use egui_extras::Size;
+    /// # use egui::{Margin, Rect, pos2};
+    /// let grid = GridBuilder::new()
+    ///     .new_row(Size::exact(40.0)).cell(Size::remainder()).named("toolbar")
+    ///     .new_row(Size::remainder())
+    ///     .cell(Size::exact(120.0)).named("sidebar").with_margin(Margin::same(4.0))
+    ///     .cell(Size::remainder()).named("content");
+    ///
+    /// let svg = grid.to_svg(Rect::from_min_max(pos2(0.0, 0.0), pos2(640.0, 480.0)));
+    /// assert!(svg.contains("sidebar"));
+    /// // std::fs::write("layout.svg", svg).unwrap();
+    /// ```
+    pub fn to_svg(&self, rect: Rect) -> String {
+        let env = Env {
+            ctx: None,
+            spacing: Style::default().spacing.item_spacing,
+        };
+        let computed = self.to_real_cells(rect, rect, Id::new("egui_grid"), &env);
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w:.2}" height="{h:.2}" viewBox="{x:.2} {y:.2} {w:.2} {h:.2}" font-family="sans-serif">"#,
+            x = rect.min.x,
+            y = rect.min.y,
+            w = rect.width(),
+            h = rect.height(),
+        );
+        // The spacing between cells shows through wherever there's nothing else
+        let _ = writeln!(svg, r##"  <rect {} fill="#d0d0d0"/>"##, area(rect));
+
+        for (outer, inner, depth) in computed.groups.iter() {
+            let color = PALETTE[depth % PALETTE.len()];
+            let _ = writeln!(
+                svg,
+                r#"  <rect {} fill="{color}" fill-opacity="0.15"/>"#,
+                area(*outer)
+            );
+            let _ = writeln!(
+                svg,
+                r#"  <rect {} fill="none" stroke="{color}" stroke-dasharray="4 2"/>"#,
+                area(*inner)
+            );
+        }
+
+        for (index, cell) in computed.cells.iter().enumerate() {
+            let color = PALETTE[cell.depth() % PALETTE.len()];
+            let _ = writeln!(
+                svg,
+                r#"  <rect {} fill="{color}" fill-opacity="0.3"/>"#,
+                area(cell.outer())
+            );
+            let _ = writeln!(
+                svg,
+                r##"  <rect {} fill="{color}" fill-opacity="0.8" stroke="#202020" stroke-width="0.5"/>"##,
+                area(cell.rect())
+            );
+
+            let label = match cell.name() {
+                Some(name) => format!("{} {}", index, escape(name)),
+                None => index.to_string(),
+            };
+            let center = cell.rect().center();
+            let size = (cell.rect().height() * 0.5).clamp(1., 12.);
+            let _ = writeln!(
+                svg,
+                r#"  <text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" dominant-baseline="middle">{}</text>"#,
+                center.x, center.y, size, label
+            );
+        }
+
+        for (line, stroke) in computed.lines.iter() {
+            let [r, g, b, a] = stroke.color.to_srgba_unmultiplied();
+            let _ = writeln!(
+                svg,
+                r#"  <line x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}" stroke="rgb({r},{g},{b})" stroke-opacity="{:.2}" stroke-width="{:.2}"/>"#,
+                line.min.x,
+                line.min.y,
+                line.max.x,
+                line.max.y,
+                a as f32 / 255.,
+                stroke.width,
+            );
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+fn area(rect: Rect) -> String {
+    format!(
+        r#"x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}""#,
+        rect.min.x,
+        rect.min.y,
+        rect.width().max(0.),
+        rect.height().max(0.)
+    )
+}
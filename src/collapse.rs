@@ -0,0 +1,75 @@
+use crate::builder::Env;
+use egui::{Context, Id};
+use std::collections::BTreeSet;
+
+// Which rows & cells of a grid are collapsed, kept in egui's memory between frames
+#[derive(Clone, Default)]
+pub(crate) struct Collapsed {
+    // Rows by the order they were allocated in
+    pub rows: BTreeSet<usize>,
+    // Cells by the row they were allocated in & their place within it
+    pub cells: BTreeSet<(usize, usize)>,
+    // Whether it was changed this frame & needs storing
+    pub changed: bool,
+}
+
+impl Collapsed {
+    pub fn load(ctx: &Context, id: Id) -> Collapsed {
+        ctx.data(|data| data.get_temp::<Collapsed>(id))
+            .unwrap_or_default()
+    }
+
+    pub fn store(mut self, ctx: &Context, id: Id) {
+        self.changed = false;
+        ctx.data_mut(|data| data.insert_temp(id, self));
+    }
+
+    pub fn set_row(&mut self, row: usize, collapsed: bool) {
+        self.changed |= if collapsed {
+            self.rows.insert(row)
+        } else {
+            self.rows.remove(&row)
+        };
+    }
+
+    pub fn set_cell(&mut self, row: usize, cell: usize, collapsed: bool) {
+        self.changed |= if collapsed {
+            self.cells.insert((row, cell))
+        } else {
+            self.cells.remove(&(row, cell))
+        };
+    }
+}
+
+// A row or cell which can collapse, and how far along it is
+#[derive(Clone, Copy)]
+pub(crate) struct Collapse {
+    // What it collapses down to
+    pub size: f32,
+    // From 0 when expanded to 1 when collapsed
+    pub progress: f32,
+}
+
+impl Collapse {
+    pub fn new(size: f32) -> Collapse {
+        Collapse {
+            size: size.max(0.),
+            progress: 0.,
+        }
+    }
+
+    // Whether it's collapsed or on its way to being collapsed
+    pub fn is_active(collapse: Option<Collapse>) -> bool {
+        collapse.is_some_and(|collapse| collapse.progress > 0.)
+    }
+
+    // Work out the progress, animating towards being collapsed (or expanded) whenever it changes
+    pub fn animate(self, id: Id, collapsed: bool, env: &Env) -> Collapse {
+        let progress = match &env.ctx {
+            Some(ctx) => ctx.animate_bool(id, collapsed),
+            None if collapsed => 1.,
+            None => 0.,
+        };
+        Collapse { progress, ..self }
+    }
+}
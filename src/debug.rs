@@ -0,0 +1,96 @@
+use crate::builder::PureCell;
+use egui::{Align2, Color32, Context, FontId, Id, LayerId, Rect, Stroke, Ui};
+use egui_extras::Size;
+
+// Where the global toggle is kept in egui's memory
+pub(crate) fn key() -> Id {
+    Id::new("egui_grid_debug")
+}
+
+pub(crate) fn enabled(ctx: &Context) -> bool {
+    ctx.data(|data| data.get_temp::<bool>(key()))
+        .unwrap_or(false)
+}
+
+// Paint the bounds, margins & spacing of every cell, along with where it's at in line & the sizes it came from
+pub(crate) fn paint(ui: &Ui, cells: &[PureCell], rows: &[Rect]) {
+    let painter = ui
+        .ctx()
+        .layer_painter(LayerId::debug())
+        .with_clip_rect(ui.clip_rect());
+    let gap_color = Color32::from_rgba_unmultiplied(255, 0, 255, 40);
+    let margin_color = Color32::from_rgba_unmultiplied(255, 160, 0, 50);
+    let cell_stroke = Stroke::new(1., Color32::from_rgb(0, 200, 255));
+    let text_color = Color32::from_rgb(0, 200, 255);
+
+    // Spacing between rows, then between cells
+    for pair in rows.windows(2) {
+        if let Some(gap) = gap(pair[0], pair[1]) {
+            painter.rect_filled(gap, 0., gap_color);
+        }
+    }
+    for pair in cells.windows(2) {
+        if pair[0].row() == pair[1].row() {
+            if let Some(gap) = gap(pair[0].outer(), pair[1].outer()) {
+                painter.rect_filled(gap, 0., gap_color);
+            }
+        }
+    }
+
+    for (index, cell) in cells.iter().enumerate() {
+        // Margins are whatever's between the outer & inner rect
+        let (outer, rect) = (cell.outer(), cell.rect());
+        for margin in [
+            Rect::from_x_y_ranges(outer.x_range(), outer.min.y..=rect.min.y),
+            Rect::from_x_y_ranges(outer.x_range(), rect.max.y..=outer.max.y),
+            Rect::from_x_y_ranges(outer.min.x..=rect.min.x, rect.y_range()),
+            Rect::from_x_y_ranges(rect.max.x..=outer.max.x, rect.y_range()),
+        ] {
+            if margin.is_positive() {
+                painter.rect_filled(margin, 0., margin_color);
+            }
+        }
+        painter.rect_stroke(rect, 0., cell_stroke);
+
+        let (row_size, cell_size) = cell.sizes();
+        let mut text = format!("#{}", index);
+        if let Some(name) = cell.name() {
+            text.push_str(&format!(" {}", name));
+        }
+        text.push_str(&format!(
+            "\nrow {} · cell {}",
+            describe(row_size),
+            describe(cell_size)
+        ));
+        painter.text(
+            rect.min + egui::vec2(2., 2.),
+            Align2::LEFT_TOP,
+            text,
+            FontId::monospace(10.),
+            text_color,
+        );
+    }
+}
+
+// The space between two rects which sit side by side (or one over the other)
+fn gap(a: Rect, b: Rect) -> Option<Rect> {
+    let x = a.x_range().intersection(b.x_range());
+    let y = a.y_range().intersection(b.y_range());
+    let (left, right) = if a.min.x <= b.min.x { (a, b) } else { (b, a) };
+    if y.span() > 0. && right.min.x > left.max.x {
+        return Some(Rect::from_x_y_ranges(left.max.x..=right.min.x, y));
+    }
+    let (top, bottom) = if a.min.y <= b.min.y { (a, b) } else { (b, a) };
+    if x.span() > 0. && bottom.min.y > top.max.y {
+        return Some(Rect::from_x_y_ranges(x, top.max.y..=bottom.min.y));
+    }
+    None
+}
+
+pub(crate) fn describe(size: Size) -> String {
+    match size {
+        Size::Absolute { initial, .. } => format!("{}px", initial),
+        Size::Relative { fraction, .. } => format!("{}%", fraction * 100.),
+        Size::Remainder { .. } => "remainder".to_string(),
+    }
+}
@@ -0,0 +1,45 @@
+use std::{fmt, path::PathBuf};
+
+/// Why a layout couldn't be loaded (or written).
+#[derive(Clone, Debug, PartialEq)]
+pub enum LayoutError {
+    /// The file couldn't be read.
+    Read {
+        /// The file being read.
+        path: PathBuf,
+        /// What went wrong.
+        message: String,
+    },
+    /// The layout isn't valid.
+    Parse {
+        /// The line the problem is on, starting from 1.
+        line: usize,
+        /// The column the problem is at, starting from 1.
+        column: usize,
+        /// What went wrong.
+        message: String,
+    },
+    /// The layout couldn't be written, see [`GridBuilder::to_ron`](crate::GridBuilder::to_ron).
+    Write {
+        /// What went wrong.
+        message: String,
+    },
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LayoutError::Read { path, message } => {
+                write!(f, "couldn't read {}: {}", path.display(), message)
+            }
+            LayoutError::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "{}:{}: {}", line, column, message),
+            LayoutError::Write { message } => write!(f, "couldn't write layout: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for LayoutError {}
@@ -0,0 +1,293 @@
+use crate::{builder::GridBuilder, error::LayoutError, grid::Grid, grid::GridResponse};
+use egui::Ui;
+use ron::{extensions::Extensions, ser::PrettyConfig, Options};
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+// How often a watched file is checked for changes
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+impl GridBuilder {
+    /// Load a layout written in [RON](https://github.com/ron-rs/ron), in the format used by the `serde` feature.
+    ///
+    /// `Some(...)` can be left out around optional values. Anything else left out is given its default, while fields the format doesn't know of are an error,
+    /// as are sizes that can't be laid out (such as a `min` more than its `max`, or a fraction outside of 0 to 1).
+    ///
+    /// ## Example
+    /// ```
+    /// # use egui_grid::{GridBuilder, LayoutError};
+    /// let grid = GridBuilder::from_ron(r#"(
+    ///     version: 1,
+    ///     grid: (
+    ///         rows: [
+    ///             (size: Exact(40.0), cells: [(name: "toolbar")]),
+    ///             (cells: [
+    ///                 (size: Exact(200.0), name: "sidebar"),
+    ///                 (name: "content"),
+    ///             ]),
+    ///         ],
+    ///     ),
+    /// )"#);
+    /// assert!(grid.is_ok());
+    ///
+    /// // Errors say where the problem is
+    /// let error = GridBuilder::from_ron("(\n    version: 1,\n    grid: (rows: [(size: Exactly(4.0))]),\n)");
+    /// assert!(matches!(error, Err(LayoutError::Parse { line: 3, .. })));
+    /// ```
+    pub fn from_ron(source: &str) -> Result<GridBuilder, LayoutError> {
+        Options::default()
+            .with_default_extension(Extensions::IMPLICIT_SOME)
+            .from_str(source)
+            .map_err(|error| LayoutError::Parse {
+                line: error.position.line,
+                column: error.position.col,
+                message: error.code.to_string(),
+            })
+    }
+
+    /// Write this layout as [RON](https://github.com/ron-rs/ron), which can be loaded again using [`Self::from_ron`].
+    ///
+    /// ## Example
+    /// ```
+    /// # use egui_grid::{GridBuilder, LayoutError};
+    /// # use egui_extras::Size;
+    /// # fn main() -> Result<(), LayoutError> {
+    /// let grid = GridBuilder::new()
+    ///     .new_row(Size::exact(40.0))
+    ///     .cell(Size::remainder())
+    ///     .named("toolbar");
+    /// let ron = grid.to_ron()?;
+    /// assert!(GridBuilder::from_ron(&ron).is_ok());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_ron(&self) -> Result<String, LayoutError> {
+        Options::default()
+            .with_default_extension(Extensions::IMPLICIT_SOME)
+            .to_string_pretty(self, PrettyConfig::default())
+            .map_err(|error| LayoutError::Write {
+                message: error.to_string(),
+            })
+    }
+}
+
+/// A layout loaded from a [RON](https://github.com/ron-rs/ron) file (see [`GridBuilder::from_ron`]), which can reload itself whenever the file changes.
+///
+/// Watching is on by default in debug builds, letting layouts be tweaked without recompiling.
+/// Should the file fail to load, the last layout that did is kept, and the error is shown over the grid by [`Self::show`] instead of panicking.
+///
+/// Since cells can come & go between reloads, filling cells using [`Grid::named`] is the way to go.
+///
+/// ## Example
+/// ```
+/// # use egui_grid::LayoutFile;
+/// # struct App { layout: LayoutFile }
+/// # fn demo(ui: &mut egui::Ui, app: &mut App) {
+/// // Kept around between frames, e.g. created alongside the app
+/// // app.layout = LayoutFile::new("assets/main.ron");
+///
+/// app.layout.show(ui, |mut grid| {
+///     grid.named("sidebar", |ui| {
+///         ui.label("Sidebar");
+///     });
+///     grid.named("content", |ui| {
+///         ui.label("Content");
+///     });
+/// });
+/// # }
+/// ```
+pub struct LayoutFile {
+    path: PathBuf,
+    watch: bool,
+    modified: Option<SystemTime>,
+    checked: Option<f64>,
+    layout: GridBuilder,
+    error: Option<LayoutError>,
+}
+
+impl LayoutFile {
+    /// Load a layout from a file. Failing to do so leaves an empty layout, along with an error.
+    pub fn new(path: impl AsRef<Path>) -> LayoutFile {
+        let mut file = LayoutFile {
+            path: path.as_ref().to_path_buf(),
+            watch: cfg!(debug_assertions),
+            modified: None,
+            checked: None,
+            layout: GridBuilder::new(),
+            error: None,
+        };
+        file.reload();
+        file
+    }
+
+    /// Should the file be reloaded whenever it changes? Default: `true` in debug builds, `false` otherwise.
+    ///
+    /// Changes are checked for twice a second while the layout is being shown.
+    pub fn watch(mut self, watch: bool) -> Self {
+        self.watch = watch;
+        self
+    }
+
+    /// Load the file again, keeping the current layout if it fails to.
+    pub fn reload(&mut self) {
+        self.modified = modified(&self.path);
+        let loaded = std::fs::read_to_string(&self.path)
+            .map_err(|error| LayoutError::Read {
+                path: self.path.clone(),
+                message: error.to_string(),
+            })
+            .and_then(|source| GridBuilder::from_ron(&source));
+        match loaded {
+            Ok(layout) => {
+                self.layout = layout;
+                self.error = None;
+            }
+            Err(error) => self.error = Some(error),
+        }
+    }
+
+    /// The most recently loaded layout.
+    pub fn layout(&self) -> &GridBuilder {
+        &self.layout
+    }
+
+    /// Why the file last failed to load, if it did.
+    pub fn error(&self) -> Option<&LayoutError> {
+        self.error.as_ref()
+    }
+
+    /// Show the layout, reloading it first if it's being watched & the file has changed since.
+    ///
+    /// Behaves like [`GridBuilder::show`], with any error painted over the grid.
+    pub fn show(&mut self, ui: &mut Ui, grid: impl FnOnce(Grid)) -> GridResponse {
+        if self.watch {
+            let time = ui.input(|input| input.time);
+            if self.checked.map_or(true, |checked| {
+                time - checked >= POLL_INTERVAL.as_secs_f64()
+            }) {
+                self.checked = Some(time);
+                if modified(&self.path) != self.modified {
+                    self.reload();
+                }
+            }
+            ui.ctx().request_repaint_after(POLL_INTERVAL);
+        }
+
+        let response = self.layout.clone().show(ui, grid);
+        if let Some(error) = &self.error {
+            let text = match error {
+                LayoutError::Parse { .. } => format!("{}:{}", self.path.display(), error),
+                LayoutError::Read { .. } | LayoutError::Write { .. } => error.to_string(),
+            };
+            ui.painter().error(response.rect.left_top(), text);
+        }
+        response
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::Env;
+    use egui::{pos2, Id, Margin, Rect, Style};
+    use egui_extras::Size;
+
+    fn rects(grid: &GridBuilder) -> Vec<Rect> {
+        let rect = Rect::from_min_max(pos2(0., 0.), pos2(640., 480.));
+        let env = Env {
+            ctx: None,
+            spacing: Style::default().spacing.item_spacing,
+        };
+        grid.to_real_cells(rect, rect, Id::new("egui_grid"), &env)
+            .cells
+            .iter()
+            .map(|cell| cell.rect())
+            .collect()
+    }
+
+    #[test]
+    fn layouts_survive_a_round_trip() {
+        let grid = GridBuilder::new()
+            .spacing(4., 6.)
+            .new_row(Size::exact(40.))
+            .sticky(true)
+            .cell(Size::remainder())
+            .named("toolbar")
+            .new_row(Size::remainder())
+            .cell(Size::relative(0.25).at_least(120.))
+            .with_margin(Margin::symmetric(8., 2.))
+            .cell(Size::remainder())
+            .nest(
+                GridBuilder::new()
+                    .new_flow(Size::exact(30.), 80., 160., 9)
+                    .new_masonry(3)
+                    .cells(Size::exact(50.), 4),
+            )
+            .new_row(Size::initial(60.).at_most(80.))
+            .cells(Size::remainder(), 3)
+            .with_collapsible(10.);
+
+        let ron = grid.to_ron().expect("layouts can always be written");
+        let loaded = GridBuilder::from_ron(&ron).expect("layouts load what they write");
+        assert_eq!(rects(&loaded), rects(&grid));
+        assert_eq!(loaded.to_ron(), Ok(ron));
+    }
+
+    // Loads a layout of a single row, returning what went wrong
+    fn load_row(row: &str) -> String {
+        match GridBuilder::from_ron(&format!("(version: 1, grid: (rows: [{row}]))")) {
+            Err(LayoutError::Parse { message, .. }) => message,
+            Err(error) => panic!("expected {row} to fail to parse, got {error}"),
+            Ok(_) => panic!("expected {row} to be rejected"),
+        }
+    }
+
+    #[test]
+    fn invalid_sizes_are_rejected() {
+        assert!(load_row("(size: Relative(fraction: NaN))").contains("fraction"));
+        assert!(load_row("(size: Relative(fraction: 1.5))").contains("fraction"));
+        assert!(load_row("(size: Exact(inf))").contains("finite"));
+        assert!(load_row("(size: Absolute(initial: 10.0, min: NaN))").contains("finite"));
+        assert!(load_row("(size: Remainder(max: NaN))").contains("finite"));
+        let message = load_row("(cells: [(), (size: Remainder(min: 50.0, max: 10.0))])");
+        assert!(message.contains("row 0, cell 1, size: min (50) is more than max (10)"));
+    }
+
+    #[test]
+    fn invalid_flows_and_masonries_are_rejected() {
+        let flow = |min: &str, max: &str| {
+            load_row(&format!(
+                "(flow: (min_width: {min}, max_width: {max}, amount: 3))"
+            ))
+        };
+        assert!(flow("80.0", "40.0").contains("flow width: min (80) is more than max (40)"));
+        assert!(flow("NaN", "40.0").contains("finite"));
+        assert!(flow("40.0", "NaN").contains("finite"));
+        assert!(load_row("(masonry: Columns(0))").contains("at least 1 column"));
+        assert!(load_row("(masonry: MinWidth(inf))").contains("finite"));
+    }
+
+    #[test]
+    fn unbounded_maximums_still_load() {
+        let grid = GridBuilder::new()
+            .new_flow(Size::exact(20.), 40., f32::INFINITY, 3)
+            .new_row(Size::remainder().at_least(10.));
+        let ron = grid.to_ron().expect("layouts can always be written");
+        assert_eq!(rects(&GridBuilder::from_ron(&ron).unwrap()), rects(&grid));
+    }
+
+    #[test]
+    fn unknown_fields_are_rejected() {
+        let error =
+            GridBuilder::from_ron("(version: 1, grid: (rows: [(cells: [(nmae: \"typo\")])]))");
+        assert!(matches!(error, Err(LayoutError::Parse { .. })));
+    }
+}
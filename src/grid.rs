@@ -1,5 +1,12 @@
-use crate::builder::PureCell;
-use egui::{Pos2, Ui};
+use crate::{
+    builder::PureCell,
+    collapse::Collapsed,
+    helper::nearest,
+    reorder::{drop_target, Move, ReorderMode},
+    selection::{Selection, SelectionMode},
+};
+use egui::{CursorIcon, EventFilter, Id, Key, Modifiers, Pos2, Rect, Response, Sense, Ui, Vec2};
+use std::ops::Deref;
 
 /// A collection of grid cells.
 ///
@@ -13,72 +20,419 @@ pub struct Grid<'a, 'b> {
     ui: &'a mut Ui,
     cells: Vec<PureCell>,
     pointer: usize,
-    bounds: &'b mut Pos2,
+    output: &'b mut Output,
 }
 
 impl Grid<'_, '_> {
     pub(crate) fn new<'a>(
         ui: &'a mut Ui,
         cells: Vec<PureCell>,
-        bounds: &'a mut Pos2,
+        output: &'a mut Output,
     ) -> Grid<'a, 'a> {
         Grid {
             ui,
             cells,
             pointer: 0,
-            bounds,
+            output,
         }
     }
 
-    /// Add contents to this cell
-    pub fn cell(&mut self, add_contents: impl FnOnce(&mut Ui)) {
-        if self.pointer > self.cells.len() - 1 {
+    /// Add contents to this cell.
+    ///
+    /// Returns the [`Response`](https://docs.rs/egui/latest/egui/struct.Response.html) of the cell itself, which will sense whatever was given to it using [`GridBuilder::with_sense`](crate::builder::GridBuilder::with_sense).
+    pub fn cell(&mut self, add_contents: impl FnOnce(&mut Ui)) -> Response {
+        if self.pointer >= self.cells.len() {
             panic!(
                 "Added more `cells` than were pre-allocated ({} pre-allocated)",
                 self.cells.len()
             );
         }
 
-        let cell = &self.cells[self.pointer];
-        let cell_rect = cell.rect();
+        let response = self.show_cell(self.pointer, add_contents);
+        self.pointer += 1;
+        response
+    }
+
+    /// Add contents to the cell given this name using [`GridBuilder::named`](crate::builder::GridBuilder::named).
+    ///
+    /// Unlike [`Self::cell`] the cell doesn't have to be next in line, and filling it won't move the line along.
+    /// Does nothing and returns `None` if no cell has been given this name, such as when it's missing from the layout picked by a [`Breakpoint`](crate::Breakpoint).
+    pub fn named(&mut self, name: &str, add_contents: impl FnOnce(&mut Ui)) -> Option<Response> {
+        let index = self
+            .cells
+            .iter()
+            .position(|cell| cell.name() == Some(name))?;
+        Some(self.show_cell(index, add_contents))
+    }
+
+    fn show_cell(&mut self, index: usize, add_contents: impl FnOnce(&mut Ui)) -> Response {
+        let cell = &self.cells[index];
+        let mut cell_rect = cell.rect();
         let cell_layout = cell.layout();
 
-        if cell_rect.max.y > self.bounds.y {
-            self.bounds.y = cell_rect.max.y;
+        if cell_rect.max.y > self.output.bounds.y {
+            self.output.bounds.y = cell_rect.max.y;
+        }
+        if cell_rect.max.x > self.output.bounds.x {
+            self.output.bounds.x = cell_rect.max.x;
+        }
+
+        // The cell is interacted with before its contents, so the contents get priority
+        let interact_rect = cell
+            .visible()
+            .map_or(cell_rect, |visible| cell_rect.intersect(visible));
+        let mut sense = cell.sense();
+        if self.output.selection_mode.is_some() || self.output.keyboard_navigation {
+            sense |= Sense::click();
+        }
+        let drag_select = self.output.selection_mode == Some(SelectionMode::Range)
+            && self.output.reorder.is_none();
+        if self.output.reorder.is_some() || drag_select {
+            sense |= Sense::drag();
+        }
+        let response = self.ui.interact(interact_rect, cell.id(), sense);
+
+        // Dragging cells (or rows) around
+        if let Some(mode) = self.output.reorder {
+            let key = match mode {
+                ReorderMode::Cells => index,
+                ReorderMode::Rows => cell.row(),
+            };
+            if response.dragged() {
+                self.output.dragging = Some(key);
+                self.ui.ctx().set_cursor_icon(CursorIcon::Grabbing);
+            } else if response.hovered() {
+                self.ui.ctx().set_cursor_icon(CursorIcon::Grab);
+            }
+            if response.drag_stopped() {
+                self.output.dropped = Some(key);
+            }
+        }
+
+        // Clicking a cell (or pressing enter/space while it's focused) activates it
+        if self.output.keyboard_navigation && response.clicked() {
+            response.request_focus();
+            self.output.activated = Some(index);
+        }
+
+        // Selecting by clicking (or dragging out a range)
+        let key = self
+            .output
+            .selection_keys
+            .get(if self.output.select_rows {
+                cell.row()
+            } else {
+                index
+            })
+            .copied();
+        if let (Some(mode), Some(key)) = (self.output.selection_mode, key) {
+            let keys = &self.output.selection_keys;
+            let selection = &mut self.output.selection;
+            let changed = if response.clicked() || (drag_select && response.drag_started()) {
+                let modifiers = self.ui.input(|input| input.modifiers);
+                selection.click(key, keys, mode, modifiers);
+                selection.dragging = drag_select && response.drag_started();
+                true
+            } else if selection.dragging && self.ui.rect_contains_pointer(interact_rect) {
+                selection.drag_to(key, keys)
+            } else {
+                false
+            };
+            if changed {
+                self.output.selection_changed = true;
+                self.ui.ctx().request_repaint();
+            }
+        }
+
+        let mut painter = self.ui.painter().clone();
+        if let Some(visible) = cell.visible() {
+            painter.set_clip_rect(visible.intersect(painter.clip_rect()));
         }
-        if cell_rect.max.x > self.bounds.x {
-            self.bounds.x = cell_rect.max.x;
+        let mut paint_rect = cell_rect;
+        let mut frame_margin = 0.;
+        if let Some(frame) = cell.frame() {
+            let frame = frame.pick(&response);
+            frame_margin = frame.outer_margin.sum().y + frame.inner_margin.sum().y;
+            paint_rect = cell_rect - frame.outer_margin;
+            painter.add(frame.paint(paint_rect));
+            cell_rect = paint_rect - frame.inner_margin;
+        }
+        // Selected rows are painted by the builder, as they span the spacing between cells
+        if !self.output.select_rows
+            && key.is_some_and(|key| self.output.selection.selected.contains(&key))
+        {
+            painter.rect_filled(paint_rect, 0., self.ui.visuals().selection.bg_fill);
         }
 
-        let mut child_ui = self.ui.child_ui(cell_rect, cell_layout, None);
+        // Named cells get an id of their own (scoped to the grid), so their contents keep their state wherever the cell ends up
+        let mut child_ui = match cell.name() {
+            Some(_) => self
+                .ui
+                .child_ui_with_id_source(cell_rect, cell_layout, cell.id(), None),
+            None => self.ui.child_ui(cell_rect, cell_layout, None),
+        };
         if cell.clip() {
             let margin = egui::Vec2::splat(self.ui.visuals().clip_rect_margin);
             let margin = margin.min(0.5 * self.ui.spacing().item_spacing);
             let clip_rect = cell_rect.expand2(margin);
             child_ui.set_clip_rect(clip_rect.intersect(child_ui.clip_rect()));
         }
+        if let Some(visible) = cell.visible() {
+            child_ui.set_clip_rect(visible.intersect(child_ui.clip_rect()));
+        }
         add_contents(&mut child_ui);
-        self.pointer += 1;
+
+        if self.output.keyboard_navigation && response.has_focus() {
+            painter.rect_stroke(paint_rect, 0., self.ui.visuals().selection.stroke);
+        }
+
+        // Remember how tall the contents were (along with their frame) for the next frame
+        if let Some(key) = cell.measure() {
+            let height = child_ui.min_rect().height() + frame_margin;
+            let previous = self.ui.data(|data| data.get_temp::<f32>(key));
+            if previous.map_or(true, |previous| (previous - height).abs() > 0.5) {
+                self.ui.data_mut(|data| data.insert_temp(key, height));
+                self.ui.ctx().request_repaint();
+            }
+        }
+
+        response
     }
 
-    /// Populate this cell with nothing. It will still take up space in the grid, but will be empty.
+    /// The index of the row the next cell in line belongs to, or `None` once every cell has been filled.
+    ///
+    /// Rows are counted as they were allocated, with flows counting once for each row they wrap onto & hidden rows not counting at all.
+    /// The cells of a nested grid belong to the row of the cell holding it.
+    pub fn row(&self) -> Option<usize> {
+        self.cells.get(self.pointer).map(|cell| cell.row())
+    }
+
+    /// Whether the next cell in line (or the row it belongs to) is selected. Always `false` unless selection is enabled using [`GridBuilder::selection`](crate::builder::GridBuilder::selection).
+    pub fn is_selected(&self) -> bool {
+        let index = if self.output.select_rows {
+            self.row()
+        } else {
+            Some(self.pointer)
+        };
+        index
+            .and_then(|index| self.output.selection_keys.get(index))
+            .is_some_and(|key| self.output.selection.selected.contains(key))
+    }
+
+    /// Replace what's selected, given the indexes of cells (or rows) in the order used by [`Self::cell`] (or [`Self::row`]).
+    ///
+    /// This is not counted as a change to the selection by [`GridResponse::selection_changed`], as it wasn't made by the user.
+    pub fn set_selection(&mut self, selected: impl IntoIterator<Item = usize>) {
+        let keys = &self.output.selection_keys;
+        self.output.selection.set(
+            selected
+                .into_iter()
+                .filter_map(|index| keys.get(index).copied()),
+        );
+    }
+
+    /// The index of the cell (or row) currently being dragged, see [`GridBuilder::reorder`](crate::builder::GridBuilder::reorder).
+    ///
+    /// Only known once the dragged cell has been filled, so check this after filling every cell.
+    pub fn dragging(&self) -> Option<usize> {
+        self.output.dragging
+    }
+
+    /// Collapse (or expand) a row made collapsible using [`GridBuilder::collapsible`](crate::builder::GridBuilder::collapsible),
+    /// going by the order rows were allocated in (a flow counting as one). Takes effect the next frame.
+    ///
+    /// Only reaches the rows of this grid, not those of any nested grids.
+    pub fn collapse_row(&mut self, row: usize, collapsed: bool) {
+        self.output.collapsed.set_row(row, collapsed);
+    }
+
+    /// Whether a row has been collapsed using [`Self::collapse_row`], even if it's still animating.
+    pub fn row_collapsed(&self, row: usize) -> bool {
+        self.output.collapsed.rows.contains(&row)
+    }
+
+    /// Collapse (or expand) a cell made collapsible using [`GridBuilder::with_collapsible`](crate::builder::GridBuilder::with_collapsible),
+    /// given the row it was allocated in & its place within that row.
+    ///
+    /// Behavior otherwise matches [`Self::collapse_row`].
+    pub fn collapse_cell(&mut self, row: usize, cell: usize, collapsed: bool) {
+        self.output.collapsed.set_cell(row, cell, collapsed);
+    }
+
+    /// Whether a cell has been collapsed using [`Self::collapse_cell`], even if it's still animating.
+    pub fn cell_collapsed(&self, row: usize, cell: usize) -> bool {
+        self.output.collapsed.cells.contains(&(row, cell))
+    }
+
+    /// Populate this cell with nothing. It will still take up space in the grid, but will be empty (aside from any frame it was given).
     pub fn empty(&mut self) {
-        if self.pointer > self.cells.len() - 1 {
+        if self.pointer >= self.cells.len() {
             panic!(
                 "Added more `cells` than were pre-allocated ({} pre-allocated)",
                 self.cells.len()
             );
         }
 
-        let cell_rect = self.cells[self.pointer].rect();
+        self.show_cell(self.pointer, |_| {});
+        self.pointer += 1;
+    }
+}
+
+// Move focus between cells using the arrow keys, going by where the cells are on screen
+pub(crate) fn navigate(ui: &Ui, cells: &[PureCell]) {
+    let current = match cells
+        .iter()
+        .position(|cell| ui.memory(|memory| memory.has_focus(cell.id())))
+    {
+        Some(current) => current,
+        None => return,
+    };
 
-        if cell_rect.max.y > self.bounds.y {
-            self.bounds.y = cell_rect.max.y;
+    // Keep egui from moving focus with the arrow keys itself
+    ui.memory_mut(|memory| {
+        memory.set_focus_lock_filter(
+            cells[current].id(),
+            EventFilter {
+                horizontal_arrows: true,
+                vertical_arrows: true,
+                ..Default::default()
+            },
+        )
+    });
+
+    let direction = ui.input_mut(|input| {
+        [
+            (Key::ArrowLeft, Vec2::LEFT),
+            (Key::ArrowRight, Vec2::RIGHT),
+            (Key::ArrowUp, Vec2::UP),
+            (Key::ArrowDown, Vec2::DOWN),
+        ]
+        .into_iter()
+        .find(|(key, _)| input.consume_key(Modifiers::NONE, *key))
+        .map(|(_, direction)| direction)
+    });
+    if let Some(direction) = direction {
+        let rects = cells.iter().map(|cell| cell.rect());
+        if let Some(next) = nearest(cells[current].rect(), rects, direction) {
+            ui.memory_mut(|memory| memory.request_focus(cells[next].id()));
         }
-        if cell_rect.max.x > self.bounds.x {
-            self.bounds.x = cell_rect.max.x;
+    }
+}
+
+// What a grid hands back to its builder once it's been filled
+#[derive(Default)]
+pub(crate) struct Output {
+    pub bounds: Pos2,
+    pub selection_mode: Option<SelectionMode>,
+    pub select_rows: bool,
+    pub selection: Selection,
+    // The ids selection is kept by, one for each cell (or row) in order
+    pub selection_keys: Vec<Id>,
+    pub selection_changed: bool,
+    pub keyboard_navigation: bool,
+    pub activated: Option<usize>,
+    pub reorder: Option<ReorderMode>,
+    pub dragging: Option<usize>,
+    pub dropped: Option<usize>,
+    pub collapsed: Collapsed,
+}
+
+impl Output {
+    // Paint where a dragged cell (or row) would be dropped, returning the move if it was dropped this frame
+    pub fn finish_drag(&self, ui: &Ui, cells: &[Rect], rows: &[Rect]) -> Option<Move> {
+        let mode = self.reorder?;
+        let from = self.dragging.or(self.dropped)?;
+        let pointer = ui.input(|input| input.pointer.interact_pos())?;
+
+        let targets = match mode {
+            ReorderMode::Cells => cells,
+            ReorderMode::Rows => rows,
+        };
+        let to = drop_target(pointer, targets.iter().copied())?;
+        if self.dragging.is_some() {
+            let stroke = ui.visuals().selection.stroke;
+            ui.painter()
+                .rect_stroke(targets[from], 0., (1., stroke.color));
+            ui.painter()
+                .rect_stroke(targets[to], 0., (stroke.width * 2., stroke.color));
         }
 
-        self.pointer += 1;
+        match self.dropped {
+            Some(_) if from != to => Some(Move { from, to }),
+            _ => None,
+        }
+    }
+}
+
+/// The response from showing a grid using [`GridBuilder::show`](crate::builder::GridBuilder::show).
+///
+/// Dereferences to the [`Response`](https://docs.rs/egui/latest/egui/struct.Response.html) of the area taken up by the grid.
+pub struct GridResponse {
+    /// The response of the area taken up by the grid.
+    pub response: Response,
+    /// The indexes of the selected cells (or rows) shown this frame, in ascending order. Empty unless selection is enabled using [`GridBuilder::selection`](crate::builder::GridBuilder::selection).
+    pub selected: Vec<usize>,
+    /// Whether the user changed the selection this frame.
+    pub selection_changed: bool,
+    /// The index of the cell the user activated this frame, by clicking it or pressing enter/space while it's focused.
+    /// Always `None` unless keyboard navigation is enabled using [`GridBuilder::keyboard_navigation`](crate::builder::GridBuilder::keyboard_navigation).
+    pub activated: Option<usize>,
+    /// The cell (or row) the user dragged to a new place this frame. Always `None` unless reordering is enabled using [`GridBuilder::reorder`](crate::builder::GridBuilder::reorder).
+    pub moved: Option<Move>,
+}
+
+impl Deref for GridResponse {
+    type Target = Response;
+
+    fn deref(&self) -> &Response {
+        &self.response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Breakpoint, GridBuilder, SelectionMode};
+    use egui_extras::Size;
+
+    #[test]
+    #[should_panic(expected = "Added more `cells` than were pre-allocated (0 pre-allocated)")]
+    fn filling_a_grid_with_every_cell_hidden_panics_with_a_message() {
+        let ctx = egui::Context::default();
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                GridBuilder::new()
+                    .new_row(Size::remainder())
+                    .row_visible(false)
+                    .cell(Size::remainder())
+                    .show(ui, |mut grid| {
+                        grid.empty();
+                    });
+            });
+        });
+    }
+
+    #[test]
+    fn breakpoint_layouts_bring_their_own_settings() {
+        let ctx = egui::Context::default();
+        let mut selected = Vec::new();
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let wide = GridBuilder::new()
+                    .new_row(Size::remainder())
+                    .cells(Size::remainder(), 2)
+                    .selection(SelectionMode::Multi);
+                selected = GridBuilder::new()
+                    .new_row(Size::remainder())
+                    .cell(Size::remainder())
+                    .breakpoint(Breakpoint::MinWidth(0.), wide)
+                    .show(ui, |mut grid| {
+                        grid.set_selection([1]);
+                        grid.empty();
+                        grid.empty();
+                    })
+                    .selected;
+            });
+        });
+        assert_eq!(selected, vec![1]);
     }
 }
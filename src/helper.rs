@@ -2,23 +2,31 @@ use crate::{
     builder::{Cell, Row},
     sizing::Sizing,
 };
-use egui::{Pos2, Rect, Vec2};
+use egui::{Align, Direction, Layout, Pos2, Rect, Vec2};
 
 // Moved code to functions so the into_real_cells method doesn't look as cluttered
 pub(crate) fn row_set_as_f32(rows: &[Row], spacing: &f32, whole: &f32) -> Vec<f32> {
     let mut row_sizes = Vec::new();
+    let mut collapse = Vec::new();
     for row in rows.iter() {
         row_sizes.push(row.size);
+        collapse.push(row.collapse);
     }
-    Sizing::from(row_sizes).to_lengths(*whole, *spacing)
+    Sizing::from(row_sizes)
+        .collapse(collapse)
+        .to_lengths(*whole, *spacing)
 }
 
 pub(crate) fn cell_set_as_f32(cells: &[Cell], spacing: &f32, whole: &f32) -> Vec<f32> {
     let mut row_sizes = Vec::new();
+    let mut collapse = Vec::new();
     for row in cells.iter() {
         row_sizes.push(row.size);
+        collapse.push(row.collapse);
     }
-    Sizing::from(row_sizes).to_lengths(*whole, *spacing)
+    Sizing::from(row_sizes)
+        .collapse(collapse)
+        .to_lengths(*whole, *spacing)
 }
 
 // This effectively reflects the rectangle on a line of symmetry where y=-x
@@ -34,6 +42,112 @@ pub(crate) fn reflect(input: Rect, focal: Pos2) -> Rect {
     }
 }
 
+// Mirror a rect within another on either axis. Axes of `around` which aren't finite are left alone
+pub(crate) fn mirror(input: Rect, around: Rect, horizontal: bool, vertical: bool) -> Rect {
+    let mut output = input;
+    if horizontal && around.x_range().span().is_finite() {
+        output.min.x = around.min.x + around.max.x - input.max.x;
+        output.max.x = around.min.x + around.max.x - input.min.x;
+    }
+    if vertical && around.y_range().span().is_finite() {
+        output.min.y = around.min.y + around.max.y - input.max.y;
+        output.max.y = around.min.y + around.max.y - input.min.y;
+    }
+    output
+}
+
+// The area to mirror a grid within: the space it was given, or the space its cells take up for any axis which isn't finite
+pub(crate) fn mirror_area(whole: Rect, cells: impl Iterator<Item = Rect>) -> Rect {
+    let content = cells.fold(Rect::NOTHING, |content, rect| content.union(rect));
+    let mut area = whole;
+    if !whole.x_range().span().is_finite() {
+        (area.min.x, area.max.x) = (content.min.x, content.max.x);
+    }
+    if !whole.y_range().span().is_finite() {
+        (area.min.y, area.max.y) = (content.min.y, content.max.y);
+    }
+    area
+}
+
+// Mirror a layout horizontally, for right-to-left grids
+pub(crate) fn mirror_layout(layout: Layout) -> Layout {
+    let mut output = layout;
+    match layout.main_dir {
+        Direction::LeftToRight => output.main_dir = Direction::RightToLeft,
+        Direction::RightToLeft => output.main_dir = Direction::LeftToRight,
+        Direction::TopDown | Direction::BottomUp => {
+            output.cross_align = match layout.cross_align {
+                Align::Min => Align::Max,
+                Align::Center => Align::Center,
+                Align::Max => Align::Min,
+            }
+        }
+    }
+    output
+}
+
+// Work out how far each sticky row has to move to stay within the viewport, without leaving the grid.
+// Also returns the edge the moved rows have covered up to, which everything else gets clipped at
+pub(crate) fn sticky_offsets(
+    rows: &[Row],
+    lengths: &[f32],
+    spacing: f32,
+    top: f32,
+    viewport: Rect,
+) -> (Vec<f32>, Option<f32>) {
+    let bottom =
+        top + lengths.iter().sum::<f32>() + spacing * lengths.len().saturating_sub(1) as f32;
+    let mut offsets = vec![0.; rows.len()];
+    let mut edge = viewport.min.y;
+    let mut covered = None;
+
+    let mut y = top;
+    for (index, row) in rows.iter().enumerate() {
+        if row.sticky {
+            let offset = (edge - y).max(0.).min(bottom - y - lengths[index]);
+            if offset > 0. {
+                offsets[index] = offset;
+                edge = y + offset + lengths[index] + spacing;
+                covered = Some(y + offset + lengths[index]);
+            }
+        }
+        y += lengths[index] + spacing;
+    }
+
+    (offsets, covered)
+}
+
+// How many cells at least `min` long fit within `length`, always at least one & never more than `most`
+pub(crate) fn fit(length: f32, min: f32, spacing: f32, most: usize) -> usize {
+    let min = min.max(1.);
+    let fits = ((length + spacing.max(0.)) / (min + spacing.max(0.))).floor();
+    if fits.is_nan() {
+        return 1;
+    }
+    (fits.min(most as f32) as usize).clamp(1, most.max(1))
+}
+
+// Find the rect closest to `from` in the given direction, favouring those lined up with it
+pub(crate) fn nearest(
+    from: Rect,
+    rects: impl Iterator<Item = Rect>,
+    direction: Vec2,
+) -> Option<usize> {
+    let mut best: Option<(usize, f32)> = None;
+    for (index, rect) in rects.enumerate() {
+        let offset = rect.center() - from.center();
+        let along = offset.dot(direction);
+        if along < 0.5 {
+            continue;
+        }
+        let score = along + (offset - direction * along).length() * 2.;
+        if best.map_or(true, |(_, best_score)| score < best_score) {
+            best = Some((index, score));
+        }
+    }
+    best.map(|(index, _)| index)
+}
+
 pub(crate) fn swap_spacing(spacing: Vec2, swap: bool) -> Vec2 {
     if swap {
         Vec2 {
@@ -44,3 +158,147 @@ pub(crate) fn swap_spacing(spacing: Vec2, swap: bool) -> Vec2 {
         spacing
     }
 }
+
+// Make text safe to put in SVG & HTML, including within attributes
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::pos2;
+    use egui_extras::Size;
+
+    fn rows(sticky: &[bool]) -> Vec<Row> {
+        sticky
+            .iter()
+            .map(|sticky| {
+                let mut row = Row::new(Size::remainder(), Align::Min);
+                row.sticky = *sticky;
+                row
+            })
+            .collect()
+    }
+
+    fn viewport(top: f32) -> Rect {
+        Rect::from_min_max(pos2(0., top), pos2(100., top + 50.))
+    }
+
+    #[test]
+    fn sticky_rows_stay_put_until_scrolled_past() {
+        let rows = rows(&[true, false, false]);
+        let lengths = [20., 100., 100.];
+        let (offsets, covered) = sticky_offsets(&rows, &lengths, 4., 0., viewport(-10.));
+        assert_eq!(offsets, vec![0., 0., 0.]);
+        assert_eq!(covered, None);
+
+        let (offsets, covered) = sticky_offsets(&rows, &lengths, 4., 0., viewport(50.));
+        assert_eq!(offsets, vec![50., 0., 0.]);
+        assert_eq!(covered, Some(70.));
+    }
+
+    #[test]
+    fn sticky_rows_stop_at_the_end_of_the_grid() {
+        let rows = rows(&[true, false, false]);
+        let lengths = [20., 100., 100.];
+        // The grid ends at 228, so the row can't move past 208
+        let (offsets, covered) = sticky_offsets(&rows, &lengths, 4., 0., viewport(300.));
+        assert_eq!(offsets, vec![208., 0., 0.]);
+        assert_eq!(covered, Some(228.));
+    }
+
+    #[test]
+    fn fit_counts_whole_cells() {
+        assert_eq!(fit(310., 100., 5., usize::MAX), 3);
+        assert_eq!(fit(50., 100., 5., usize::MAX), 1);
+        assert_eq!(fit(310., 100., 5., 2), 2);
+    }
+
+    #[test]
+    fn fit_survives_zero_sizes() {
+        // Cells with no minimum are treated as 1 point long, rather than fitting infinitely many
+        assert_eq!(fit(100., 0., 0., usize::MAX), 100);
+        assert_eq!(fit(100., -5., 0., usize::MAX), 100);
+        assert_eq!(fit(f32::INFINITY, 0., 0., 8), 8);
+        assert_eq!(fit(f32::NAN, 10., 0., 8), 1);
+    }
+
+    #[test]
+    fn sticky_rows_stack() {
+        let rows = rows(&[true, false, true, false]);
+        let lengths = [20., 50., 10., 200.];
+        // The second sticky row is pushed under the first, rather than covering it
+        let (offsets, covered) = sticky_offsets(&rows, &lengths, 0., 0., viewport(100.));
+        assert_eq!(offsets, vec![100., 0., 50., 0.]);
+        assert_eq!(covered, Some(130.));
+    }
+
+    #[test]
+    fn rects_mirror_within_each_other() {
+        let around = Rect::from_min_max(pos2(10., 20.), pos2(110., 220.));
+        let input = Rect::from_min_max(pos2(10., 20.), pos2(40., 70.));
+        assert_eq!(mirror(input, around, false, false), input);
+        assert_eq!(
+            mirror(input, around, true, false),
+            Rect::from_min_max(pos2(80., 20.), pos2(110., 70.))
+        );
+        assert_eq!(
+            mirror(input, around, false, true),
+            Rect::from_min_max(pos2(10., 170.), pos2(40., 220.))
+        );
+        // Mirroring twice puts it back where it was
+        let both = mirror(input, around, true, true);
+        assert_eq!(both, Rect::from_min_max(pos2(80., 170.), pos2(110., 220.)));
+        assert_eq!(mirror(both, around, true, true), input);
+    }
+
+    #[test]
+    fn unbounded_axes_mirror_around_the_cells() {
+        // As in a vertical ScrollArea
+        let whole = Rect::from_min_max(pos2(0., 0.), pos2(100., f32::INFINITY));
+        let input = Rect::from_min_max(pos2(0., 0.), pos2(40., 20.));
+        // Left alone on the unbounded axis
+        assert_eq!(mirror(input, whole, false, true), input);
+
+        let cells = [input, Rect::from_min_max(pos2(0., 20.), pos2(40., 50.))];
+        let area = mirror_area(whole, cells.into_iter());
+        assert_eq!(area, Rect::from_min_max(pos2(0., 0.), pos2(100., 50.)));
+        assert_eq!(
+            mirror(input, area, true, true),
+            Rect::from_min_max(pos2(60., 30.), pos2(100., 50.))
+        );
+
+        // Bounded axes stay as they were given
+        let whole = Rect::from_min_max(pos2(0., 0.), pos2(100., 200.));
+        assert_eq!(mirror_area(whole, cells.into_iter()), whole);
+    }
+
+    #[test]
+    fn layouts_mirror_horizontally() {
+        assert_eq!(
+            mirror_layout(Layout::left_to_right(Align::Center)),
+            Layout::right_to_left(Align::Center)
+        );
+        assert_eq!(
+            mirror_layout(Layout::right_to_left(Align::Min)),
+            Layout::left_to_right(Align::Min)
+        );
+        // Vertical layouts swap which side they're aligned to
+        assert_eq!(
+            mirror_layout(Layout::top_down(Align::Min)),
+            Layout::top_down(Align::Max)
+        );
+        assert_eq!(
+            mirror_layout(Layout::bottom_up(Align::Max)),
+            Layout::bottom_up(Align::Min)
+        );
+        assert_eq!(
+            mirror_layout(Layout::top_down(Align::Center)),
+            Layout::top_down(Align::Center)
+        );
+    }
+}
@@ -3,13 +3,32 @@
 //!
 //! Relies on structs and enums from both [`egui`](https://github.com/emilk/egui) and [`egui_extras`](https://crates.io/crates/egui_extras).
 //! 
-//! This crate includes 2 items, [`GridBuilder`] and [`Grid`], which are used to create grids
+//! This crate is built around 2 items, [`GridBuilder`] and [`Grid`], which are used to create grids
 //! with behavior similar to the StripBuilder found in eui_extras, though being much more compact and with additional features.
 //!
+//! Enable the `serde` feature to save & load layouts, or the `ron` feature to load them from [RON](https://github.com/ron-rs/ron) files using `LayoutFile`.
+//!
 mod sizing;
 mod grid;
 mod helper;
 mod builder;
+mod style;
+mod selection;
+mod reorder;
+mod macros;
+mod error;
+mod ascii;
+mod debug;
+mod collapse;
+mod transition;
+#[cfg(feature = "ron")]
+mod file;
 
-pub use crate::grid::Grid;
-pub use crate::builder::GridBuilder;
+pub use crate::grid::{Grid, GridResponse};
+pub use crate::builder::{Breakpoint, CellMut, GridBuilder, LayoutEditor, Orientation};
+pub use crate::style::{CellFrame, Separators};
+pub use crate::selection::SelectionMode;
+pub use crate::reorder::{Move, ReorderMode};
+pub use crate::error::LayoutError;
+#[cfg(feature = "ron")]
+pub use crate::file::LayoutFile;
@@ -0,0 +1,119 @@
+/// Build a [`GridBuilder`](crate::GridBuilder) from a nested block of rows & cells, instead of a chain of calls.
+///
+/// - `row(size) { .. }` allocates a row, see [`GridBuilder::new_row`](crate::GridBuilder::new_row). Any arguments are passed along, so `row(size, align)` works too.
+/// - `masonry(columns) { .. }` & `masonry_auto(min_width) { .. }` allocate a masonry, see [`GridBuilder::new_masonry`](crate::GridBuilder::new_masonry).
+/// - `flow(height, min_width, max_width, amount);` allocates a flow, see [`GridBuilder::new_flow`](crate::GridBuilder::new_flow). Having no cells to list, it ends with a semicolon instead.
+/// - Within a row, `cell(size)` & `cells(size, amount)` are separated by commas. A cell followed by a block has a grid nested in it, written the same way.
+///
+/// Rows & cells can be followed by any of the builder's methods, which apply to them as they would in a chain (e.g. `.named("sidebar")` or `.with_margin(margin)`).
+/// Settings for the grid as a whole are chained onto the end of the macro.
+///
+/// Cells can't span several rows or columns; nest a grid within a cell instead.
+///
+/// ## Example
+/// ```
+/// use egui_grid::grid;
+/// use egui_extras::Size;
+/// use egui::{Align, Margin};
+///
+/// # fn demo(ui: &mut egui::Ui) {
+/// grid! {
+///     row(Size::exact(40.0)).sticky(true) {
+///         cell(Size::remainder()).named("toolbar"),
+///     }
+///     row(Size::remainder()) {
+///         cell(Size::exact(200.0)).named("sidebar").with_margin(Margin::same(4.0)),
+///         // A cell with a grid nested in it
+///         cell(Size::remainder()) {
+///             row(Size::remainder()) {
+///                 cell(Size::remainder()).named("content"),
+///             }
+///             row(Size::exact(120.0), Align::Center) {
+///                 cells(Size::exact(80.0), 3),
+///             }
+///         },
+///     }
+/// }
+/// .spacing(4.0, 4.0)
+/// .show(ui, |mut grid| {
+///     grid.named("toolbar", |ui| {
+///         ui.label("Toolbar");
+///     });
+///     grid.named("sidebar", |ui| {
+///         ui.label("Sidebar");
+///     });
+///     grid.named("content", |ui| {
+///         ui.label("Content");
+///     });
+/// });
+/// # }
+/// ```
+///
+/// Malformed layouts are caught when compiling:
+/// ```compile_fail
+/// # use egui_grid::grid;
+/// # use egui_extras::Size;
+/// let layout = grid! {
+///     row(Size::remainder()) {
+///         // Missing a comma between the cells
+///         cell(Size::remainder()) cell(Size::remainder())
+///     }
+/// };
+/// ```
+#[macro_export]
+macro_rules! grid {
+    // Rows
+    (@row $grid:expr; $size:expr, $align:expr $(,)?) => {
+        $grid.new_row_align($size, $align)
+    };
+    (@row $grid:expr; $size:expr $(,)?) => {
+        $grid.new_row($size)
+    };
+    (@rows $grid:expr;) => {
+        $grid
+    };
+    (@rows $grid:expr; row ( $($size:tt)* ) $( . $method:ident ( $($args:tt)* ) )* { $($cells:tt)* } $($rest:tt)*) => {
+        $crate::grid!(@rows $crate::grid!(@cells $crate::grid!(@row $grid; $($size)*) $( . $method ( $($args)* ) )*; $($cells)*); $($rest)*)
+    };
+    (@rows $grid:expr; masonry ( $($columns:tt)* ) $( . $method:ident ( $($args:tt)* ) )* { $($cells:tt)* } $($rest:tt)*) => {
+        $crate::grid!(@rows $crate::grid!(@cells $grid.new_masonry($($columns)*) $( . $method ( $($args)* ) )*; $($cells)*); $($rest)*)
+    };
+    (@rows $grid:expr; masonry_auto ( $($min_width:tt)* ) $( . $method:ident ( $($args:tt)* ) )* { $($cells:tt)* } $($rest:tt)*) => {
+        $crate::grid!(@rows $crate::grid!(@cells $grid.new_masonry_auto($($min_width)*) $( . $method ( $($args)* ) )*; $($cells)*); $($rest)*)
+    };
+    (@rows $grid:expr; flow ( $($args:tt)* ) $( . $method:ident ( $($method_args:tt)* ) )* ; $($rest:tt)*) => {
+        $crate::grid!(@rows $grid.new_flow($($args)*) $( . $method ( $($method_args)* ) )*; $($rest)*)
+    };
+    (@rows $grid:expr; $($rest:tt)*) => {
+        compile_error!(concat!(
+            "expected `row(size) { .. }`, `masonry(columns) { .. }`, `masonry_auto(min_width) { .. }` or `flow(height, min_width, max_width, amount);`, found `",
+            stringify!($($rest)*),
+            "`"
+        ))
+    };
+
+    // Cells
+    (@cells $grid:expr;) => {
+        $grid
+    };
+    (@cells $grid:expr; cell ( $($size:tt)* ) $( . $method:ident ( $($args:tt)* ) )* { $($nested:tt)* } $(, $($rest:tt)*)?) => {
+        $crate::grid!(@cells $grid.cell($($size)*).nest($crate::grid!($($nested)*)) $( . $method ( $($args)* ) )*; $($($rest)*)?)
+    };
+    (@cells $grid:expr; cell ( $($size:tt)* ) $( . $method:ident ( $($args:tt)* ) )* $(, $($rest:tt)*)?) => {
+        $crate::grid!(@cells $grid.cell($($size)*) $( . $method ( $($args)* ) )*; $($($rest)*)?)
+    };
+    (@cells $grid:expr; cells ( $($args:tt)* ) $( . $method:ident ( $($method_args:tt)* ) )* $(, $($rest:tt)*)?) => {
+        $crate::grid!(@cells $grid.cells($($args)*) $( . $method ( $($method_args)* ) )*; $($($rest)*)?)
+    };
+    (@cells $grid:expr; $($rest:tt)*) => {
+        compile_error!(concat!(
+            "expected `cell(size)` or `cells(size, amount)`, separated by commas, found `",
+            stringify!($($rest)*),
+            "`"
+        ))
+    };
+
+    ($($layout:tt)*) => {
+        $crate::grid!(@rows $crate::GridBuilder::new(); $($layout)*)
+    };
+}
@@ -0,0 +1,55 @@
+use egui::{Pos2, Rect};
+
+/// What the user can rearrange by dragging, set with [`GridBuilder::reorder`](crate::GridBuilder::reorder).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReorderMode {
+    /// Cells can be dragged onto other cells.
+    Cells,
+    /// Rows can be dragged onto other rows, by dragging any cell within them.
+    Rows,
+}
+
+/// A cell (or row) the user dragged to a new place, returned by [`GridBuilder::show`](crate::GridBuilder::show).
+///
+/// Cells are given by their index in the order used by [`Grid::cell`](crate::Grid::cell), and rows by [`Grid::row`](crate::Grid::row).
+/// The grid itself is left as it was, it's up to the app to rearrange whatever the cells (or rows) are showing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Move {
+    /// Where the cell (or row) was dragged from.
+    pub from: usize,
+    /// Where it was dropped.
+    pub to: usize,
+}
+
+impl Move {
+    /// Apply the move to a list of items, taking out the item at `from` and putting it back in at `to`.
+    /// Does nothing if either is out of bounds.
+    ///
+    /// ## Example
+    /// ```
+    /// # use egui_grid::Move;
+    /// let mut tiles = vec!["a", "b", "c", "d"];
+    /// Move { from: 0, to: 2 }.apply(&mut tiles);
+    /// assert_eq!(tiles, ["b", "c", "a", "d"]);
+    /// ```
+    pub fn apply<T>(&self, items: &mut Vec<T>) {
+        if self.from < items.len() && self.to < items.len() {
+            let item = items.remove(self.from);
+            items.insert(self.to, item);
+        }
+    }
+}
+
+// Find which of the rects the pointer is over, going with the closest one if it's over none of them
+pub(crate) fn drop_target(pointer: Pos2, rects: impl Iterator<Item = Rect>) -> Option<usize> {
+    let mut best: Option<(usize, f32)> = None;
+    for (index, rect) in rects.enumerate() {
+        let distance = rect.distance_sq_to_pos(pointer);
+        if best.map_or(true, |(_, best_distance)| distance < best_distance) {
+            best = Some((index, distance));
+        }
+    }
+    best.map(|(index, _)| index)
+}
@@ -0,0 +1,149 @@
+use egui::{Context, Id, Modifiers};
+use std::collections::HashSet;
+
+/// How the cells (or rows) of a grid can be selected by clicking them, set with [`GridBuilder::selection`](crate::GridBuilder::selection).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SelectionMode {
+    /// Only one can be selected at a time.
+    Single,
+    /// Any amount can be selected.
+    ///
+    /// Clicking selects just the one clicked, holding ctrl (cmd on mac) adds or removes it from the selection instead,
+    /// and holding shift selects everything from the last one clicked up to it.
+    Multi,
+    /// A single unbroken run can be selected.
+    ///
+    /// Clicking selects just the one clicked, and holding shift while clicking (or dragging across cells) selects everything from the last one clicked up to it.
+    /// Dragging doesn't extend the selection when cells can be reordered, see [`GridBuilder::reorder`](crate::GridBuilder::reorder).
+    Range,
+}
+
+// The selection of a grid, kept in egui's memory between frames.
+// Cells (or rows) are kept by their id, with `keys` being the ids of every cell (or row) shown this frame in order
+#[derive(Clone, Default)]
+pub(crate) struct Selection {
+    pub selected: HashSet<Id>,
+    // The last one clicked, where shift-click ranges start from
+    anchor: Option<Id>,
+    // Whether a range is being dragged out
+    pub dragging: bool,
+}
+
+impl Selection {
+    pub fn load(ctx: &Context, id: Id) -> Selection {
+        ctx.data(|data| data.get_temp::<Selection>(id))
+            .unwrap_or_default()
+    }
+
+    pub fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|data| data.insert_temp(id, self));
+    }
+
+    // The indexes of what's selected among those shown this frame, in ascending order
+    pub fn indexes(&self, keys: &[Id]) -> Vec<usize> {
+        (0..keys.len())
+            .filter(|index| self.selected.contains(&keys[*index]))
+            .collect()
+    }
+
+    // Replace the selection, keeping the anchor if it's still selected
+    pub fn set(&mut self, selected: impl IntoIterator<Item = Id>) {
+        let selected: Vec<Id> = selected.into_iter().collect();
+        if !self.anchor.is_some_and(|anchor| selected.contains(&anchor)) {
+            self.anchor = selected.first().copied();
+        }
+        self.selected = selected.into_iter().collect();
+    }
+
+    pub fn click(&mut self, key: Id, keys: &[Id], mode: SelectionMode, modifiers: Modifiers) {
+        match (mode, self.anchor) {
+            (SelectionMode::Multi | SelectionMode::Range, Some(anchor)) if modifiers.shift => {
+                if mode == SelectionMode::Range || !modifiers.command {
+                    self.selected.clear();
+                }
+                self.selected.extend(range(anchor, key, keys));
+            }
+            (SelectionMode::Multi, _) if modifiers.command => {
+                if !self.selected.remove(&key) {
+                    self.selected.insert(key);
+                }
+                self.anchor = Some(key);
+            }
+            _ => {
+                self.selected = HashSet::from([key]);
+                self.anchor = Some(key);
+            }
+        }
+    }
+
+    // Stretch the range from the anchor to `key`, returning whether that changed anything
+    pub fn drag_to(&mut self, key: Id, keys: &[Id]) -> bool {
+        let Some(anchor) = self.anchor else {
+            return false;
+        };
+        let selected: HashSet<Id> = range(anchor, key, keys).into_iter().collect();
+        if selected == self.selected {
+            return false;
+        }
+        self.selected = selected;
+        true
+    }
+}
+
+// Everything from `from` up to `to` (in either direction), or just `to` if either isn't shown anymore
+fn range(from: Id, to: Id, keys: &[Id]) -> Vec<Id> {
+    let position = |id: Id| keys.iter().position(|key| *key == id);
+    match (position(from), position(to)) {
+        (Some(from), Some(to)) => keys[from.min(to)..=from.max(to)].to_vec(),
+        _ => vec![to],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(names: &[&str]) -> Vec<Id> {
+        names.iter().map(Id::new).collect()
+    }
+
+    #[test]
+    fn ranges_stay_unbroken() {
+        let keys = keys(&["a", "b", "c", "d", "e"]);
+        let shift = Modifiers::SHIFT;
+        let mut selection = Selection::default();
+        selection.click(keys[1], &keys, SelectionMode::Range, Modifiers::NONE);
+        selection.click(keys[3], &keys, SelectionMode::Range, shift);
+        assert_eq!(selection.indexes(&keys), vec![1, 2, 3]);
+
+        // Holding ctrl doesn't toggle single cells in & out
+        selection.click(keys[0], &keys, SelectionMode::Range, Modifiers::COMMAND);
+        assert_eq!(selection.indexes(&keys), vec![0]);
+        selection.click(
+            keys[2],
+            &keys,
+            SelectionMode::Range,
+            shift | Modifiers::COMMAND,
+        );
+        assert_eq!(selection.indexes(&keys), vec![0, 1, 2]);
+
+        // Dragging stretches the range from where it started
+        assert!(selection.drag_to(keys[4], &keys));
+        assert_eq!(selection.indexes(&keys), vec![0, 1, 2, 3, 4]);
+        assert!(!selection.drag_to(keys[4], &keys));
+    }
+
+    #[test]
+    fn selection_follows_ids() {
+        let mut selection = Selection::default();
+        let before = keys(&["a", "b", "c"]);
+        selection.click(before[1], &before, SelectionMode::Single, Modifiers::NONE);
+
+        // The selected cell moved to the front, & another one was added
+        let after = keys(&["b", "d", "a", "c"]);
+        assert_eq!(selection.indexes(&after), vec![0]);
+        selection.click(after[3], &after, SelectionMode::Multi, Modifiers::SHIFT);
+        assert_eq!(selection.indexes(&after), vec![0, 1, 2, 3]);
+    }
+}
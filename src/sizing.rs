@@ -1,11 +1,14 @@
 // I took this from egui's source
 // It turns Size into actual tangible numbers and I really really wasn't going to go about remaking this myself
 
+use crate::collapse::Collapse;
 use egui_extras::Size;
 
 #[derive(Clone, Default)]
 pub struct Sizing {
     pub(crate) sizes: Vec<Size>,
+    // Which of the sizes can collapse, if any
+    pub(crate) collapse: Vec<Option<Collapse>>,
 }
 
 impl Sizing {
@@ -14,7 +17,40 @@ impl Sizing {
     //    self.sizes.push(size);
     //}
 
+    pub fn collapse(mut self, collapse: Vec<Option<Collapse>>) -> Self {
+        self.collapse = collapse;
+        self
+    }
+
+    // Anything collapsing is fixed somewhere between its usual length & what it collapses down to,
+    // with the space this frees up going to any remainders
     pub fn to_lengths(&self, length: f32, spacing: f32) -> Vec<f32> {
+        let lengths = self.natural_lengths(length, spacing);
+        if self
+            .collapse
+            .iter()
+            .flatten()
+            .all(|collapse| collapse.progress <= 0.)
+        {
+            return lengths;
+        }
+
+        let sizes = self
+            .sizes
+            .iter()
+            .zip(lengths)
+            .zip(self.collapse.iter().chain(std::iter::repeat(&None)))
+            .map(|((&size, natural), collapse)| match collapse {
+                Some(collapse) if collapse.progress > 0. => {
+                    Size::exact(egui::lerp(natural..=collapse.size, collapse.progress))
+                }
+                _ => size,
+            })
+            .collect::<Vec<_>>();
+        Sizing::from(sizes).natural_lengths(length, spacing)
+    }
+
+    fn natural_lengths(&self, length: f32, spacing: f32) -> Vec<f32> {
         if self.sizes.is_empty() {
             return vec![];
         }
@@ -70,6 +106,30 @@ impl Sizing {
 
 impl From<Vec<Size>> for Sizing {
     fn from(sizes: Vec<Size>) -> Self {
-        Self { sizes }
+        Self {
+            sizes,
+            collapse: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lengths(progress: f32) -> Vec<f32> {
+        let mut collapse = Collapse::new(20.);
+        collapse.progress = progress;
+        Sizing::from(vec![Size::exact(100.), Size::remainder(), Size::exact(50.)])
+            .collapse(vec![Some(collapse), None, None])
+            .to_lengths(400., 10.)
+    }
+
+    #[test]
+    fn collapsing_frees_up_space_for_remainders() {
+        assert_eq!(lengths(0.), vec![100., 230., 50.]);
+        // Halfway between its usual length & what it collapses down to
+        assert_eq!(lengths(0.5), vec![60., 270., 50.]);
+        assert_eq!(lengths(1.), vec![20., 310., 50.]);
     }
 }
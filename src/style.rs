@@ -0,0 +1,227 @@
+use egui::{Frame, Pos2, Rect, Response, Stroke};
+
+/// How a cell gets painted, given to a grid using [`GridBuilder::frame`](crate::GridBuilder::frame), [`GridBuilder::row_frame`](crate::GridBuilder::row_frame) or [`GridBuilder::with_frame`](crate::GridBuilder::with_frame).
+///
+/// Frames are painted behind the contents of a cell, with the frame's inner margin padding the contents.
+/// Can be created from a plain [`Frame`](https://docs.rs/egui/latest/egui/containers/struct.Frame.html), which will be used no matter the state of the cell.
+///
+/// ## Example
+/// ```
+/// # use egui_grid::CellFrame;
+/// # use egui::{Color32, Frame, Margin, Rounding, Stroke};
+/// let card = Frame::none()
+///     .fill(Color32::from_gray(40))
+///     .rounding(Rounding::same(4.0))
+///     .inner_margin(Margin::same(6.0));
+///
+/// let frame = CellFrame::new(card)
+///     .hovered(card.stroke(Stroke::new(1.0, Color32::from_gray(120))))
+///     .active(card.fill(Color32::from_gray(60)));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CellFrame {
+    /// Used while the cell is left alone.
+    pub frame: Frame,
+    /// Used instead while the pointer is over the cell.
+    pub hovered: Option<Frame>,
+    /// Used instead while the cell is being clicked or dragged. Only cells given a sense that clicks or drags will ever be active (see [`GridBuilder::with_sense`](crate::GridBuilder::with_sense)).
+    pub active: Option<Frame>,
+}
+
+impl CellFrame {
+    /// Create a new cell frame, using `frame` while the cell is left alone.
+    pub fn new(frame: Frame) -> CellFrame {
+        CellFrame {
+            frame,
+            hovered: None,
+            active: None,
+        }
+    }
+
+    /// The frame used while the pointer is over the cell.
+    pub fn hovered(mut self, frame: Frame) -> Self {
+        self.hovered = Some(frame);
+        self
+    }
+
+    /// The frame used while the cell is being clicked or dragged.
+    pub fn active(mut self, frame: Frame) -> Self {
+        self.active = Some(frame);
+        self
+    }
+
+    // Pick the frame matching the state of the cell
+    pub(crate) fn pick(&self, response: &Response) -> Frame {
+        if response.is_pointer_button_down_on() || response.dragged() {
+            if let Some(frame) = self.active {
+                return frame;
+            }
+        }
+        if response.hovered() {
+            if let Some(frame) = self.hovered {
+                return frame;
+            }
+        }
+        self.frame
+    }
+}
+
+impl From<Frame> for CellFrame {
+    fn from(frame: Frame) -> Self {
+        CellFrame::new(frame)
+    }
+}
+
+/// Lines painted in the spacing between the rows & cells of a grid, given to a grid using [`GridBuilder::separators`](crate::GridBuilder::separators).
+///
+/// Lines are painted down the middle of the spacing, so give the grid some spacing to keep them from touching the cells.
+///
+/// ## Example
+/// ```
+/// # use egui_grid::Separators;
+/// # use egui::{Color32, Stroke};
+/// let stroke = Stroke::new(1.0, Color32::GRAY);
+///
+/// // Lines between rows and cells, shortened by 4 points on each end
+/// let all = Separators::new(stroke).inset(4.0);
+/// // Only lines between rows
+/// let rows = Separators::new(stroke).cells(false);
+/// // Only a border around the grid
+/// let border = Separators::new(stroke).rows(false).cells(false).border(true);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Separators {
+    /// The stroke lines are painted with.
+    pub stroke: Stroke,
+    /// How much lines are shortened by on each end. Does not affect the border.
+    pub inset: f32,
+    /// Paint lines between rows.
+    pub between_rows: bool,
+    /// Paint lines between the cells within a row.
+    pub between_cells: bool,
+    /// Paint a border around the grid.
+    pub border: bool,
+}
+
+impl Separators {
+    /// Create new separators, painting lines between rows & cells (but no border).
+    pub fn new(stroke: impl Into<Stroke>) -> Separators {
+        Separators {
+            stroke: stroke.into(),
+            inset: 0.,
+            between_rows: true,
+            between_cells: true,
+            border: false,
+        }
+    }
+
+    /// How much lines are shortened by on each end. Default: `0.0`.
+    pub fn inset(mut self, inset: f32) -> Self {
+        self.inset = inset;
+        self
+    }
+
+    /// Should lines be painted between rows? Default: `true`.
+    pub fn rows(mut self, rows: bool) -> Self {
+        self.between_rows = rows;
+        self
+    }
+
+    /// Should lines be painted between the cells within a row? Default: `true`.
+    pub fn cells(mut self, cells: bool) -> Self {
+        self.between_cells = cells;
+        self
+    }
+
+    /// Should a border be painted around the grid? Default: `false`.
+    pub fn border(mut self, border: bool) -> Self {
+        self.border = border;
+        self
+    }
+
+    // Work out where lines go, given the rect of each row along with the cells within it & how far its sticky rows & frozen cells have moved.
+    // Lines are returned as rects with no width or height, going from min to max
+    pub(crate) fn lines(&self, rows: &[SeparatorRow], covered_y: Option<f32>) -> Vec<Rect> {
+        let content = rows
+            .iter()
+            .fold(Rect::NOTHING, |content, row| content.union(row.rect));
+        let mut lines = Vec::new();
+
+        if self.between_rows {
+            for pair in rows.windows(2) {
+                // Lines follow whichever of the two rows has been moved the furthest, and get covered by sticky rows otherwise
+                let y = (pair[0].rect.max.y + pair[1].rect.min.y) * 0.5
+                    + pair[0].offset.max(pair[1].offset);
+                if !(pair[0].sticky || pair[1].sticky)
+                    && covered_y.is_some_and(|covered| y < covered)
+                {
+                    continue;
+                }
+                lines.push(Rect::from_min_max(
+                    Pos2::new(content.min.x + self.inset, y),
+                    Pos2::new(content.max.x - self.inset, y),
+                ));
+            }
+        }
+        if self.between_cells {
+            for row in rows {
+                let mut min_y = row.rect.min.y + row.offset + self.inset;
+                let max_y = row.rect.max.y + row.offset - self.inset;
+                if let (false, Some(covered)) = (row.sticky, covered_y) {
+                    min_y = min_y.max(covered);
+                }
+                if min_y > max_y {
+                    continue;
+                }
+                // Lines after frozen cells move along with them, and the rest get covered by them
+                let covered_x = match (row.frozen_offset > 0., row.frozen.checked_sub(1)) {
+                    (true, Some(last)) => row
+                        .cells
+                        .get(last)
+                        .map(|cell| cell.max.x + row.frozen_offset),
+                    _ => None,
+                };
+                for (index, pair) in row.cells.windows(2).enumerate() {
+                    let mut x = (pair[0].max.x + pair[1].min.x) * 0.5;
+                    if index < row.frozen {
+                        x += row.frozen_offset;
+                    } else if covered_x.is_some_and(|covered| x < covered) {
+                        continue;
+                    }
+                    lines.push(Rect::from_min_max(Pos2::new(x, min_y), Pos2::new(x, max_y)));
+                }
+            }
+        }
+        if self.border && content.is_positive() {
+            lines.push(Rect::from_min_max(content.left_top(), content.right_top()));
+            lines.push(Rect::from_min_max(
+                content.right_top(),
+                content.right_bottom(),
+            ));
+            lines.push(Rect::from_min_max(
+                content.left_bottom(),
+                content.right_bottom(),
+            ));
+            lines.push(Rect::from_min_max(
+                content.left_top(),
+                content.left_bottom(),
+            ));
+        }
+
+        lines
+    }
+}
+
+// A row as the separators see it: where it was placed, before any sticky rows or frozen cells were moved
+pub(crate) struct SeparatorRow {
+    pub rect: Rect,
+    pub cells: Vec<Rect>,
+    pub sticky: bool,
+    // How far the row was moved down to stay in view
+    pub offset: f32,
+    // How many of the row's leading cells are frozen, & how far they were moved to stay in view
+    pub frozen: usize,
+    pub frozen_offset: f32,
+}
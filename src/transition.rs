@@ -0,0 +1,58 @@
+use egui::{emath::easing, Context, Id, Pos2, Rect};
+
+// Where a cell is moving from & to, relative to the top-left of its grid
+#[derive(Clone, Copy)]
+struct Transition {
+    from: Rect,
+    to: Rect,
+    start: f64,
+}
+
+impl Transition {
+    // Where the cell is at a given time, and whether it's still moving
+    fn at(&self, time: f64, duration: f32) -> (Rect, bool) {
+        let t = if duration > 0. {
+            ((time - self.start) / duration as f64).clamp(0., 1.) as f32
+        } else {
+            1.
+        };
+        let eased = easing::cubic_out(t);
+        let rect = Rect::from_min_max(
+            self.from.min.lerp(self.to.min, eased),
+            self.from.max.lerp(self.to.max, eased),
+        );
+        (rect, t < 1.)
+    }
+}
+
+// Move a cell towards its rect over `duration` seconds, starting from wherever it was last frame.
+// Rects are remembered relative to `origin`, so the grid itself moving (e.g. being scrolled) isn't animated
+pub(crate) fn transition(ctx: &Context, id: Id, rect: Rect, origin: Pos2, duration: f32) -> Rect {
+    let key = id.with("transition");
+    let time = ctx.input(|input| input.time);
+    let target = rect.translate(-origin.to_vec2());
+
+    let previous = ctx.data(|data| data.get_temp::<Transition>(key));
+    let transition = match previous {
+        Some(previous) if previous.to == target => previous,
+        Some(previous) => Transition {
+            from: previous.at(time, duration).0,
+            to: target,
+            start: time,
+        },
+        None => Transition {
+            from: target,
+            to: target,
+            start: time,
+        },
+    };
+    if previous.map_or(true, |previous| previous.to != target) {
+        ctx.data_mut(|data| data.insert_temp(key, transition));
+    }
+
+    let (rect, moving) = transition.at(time, duration);
+    if moving {
+        ctx.request_repaint();
+    }
+    rect.translate(origin.to_vec2())
+}